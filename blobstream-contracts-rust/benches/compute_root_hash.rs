@@ -0,0 +1,152 @@
+//! Compares the Wasm instructions `binary_merkle_tree::compute_root_hash` executes per proof with the
+//! previous recursive implementation, which did its index math in `U256` and cloned the side nodes
+//! at every level.
+//!
+//! Run with `cargo bench -p blobstream_contracts_rust --bench compute_root_hash`, it needs the
+//! `wasm32-unknown-unknown` target. The bench builds the `compute_root_hash_wasm` example and runs it
+//! in wasmi with fuel metering, which consumes one unit of fuel per executed Wasm operator except the
+//! structured control markers (`block`, `loop`, `end`, ...). Both implementations hash the same
+//! nodes, the difference is the index math and copying.
+use blobstream_contracts_rust::binary_merkle_tree::{leaf_digest, MerkleTree};
+use seq_wasm_sdk::Bytes;
+use std::path::Path;
+use std::process::Command;
+use wasmi::{Config, Engine, ExternType, Instance, Linker, Memory, Store};
+
+/// Builds the release Wasm module of the `compute_root_hash_wasm` example and returns its bytes.
+fn build_module() -> Vec<u8> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // a separate target directory, the one of the bench is locked while it runs.
+    let target_dir = manifest_dir.join("../target/compute-root-hash-wasm");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .current_dir(manifest_dir)
+        .args(["build", "--release", "--target", "wasm32-unknown-unknown"])
+        .args(["--example", "compute_root_hash_wasm", "--target-dir"])
+        .arg(&target_dir)
+        .status()
+        .expect("cargo runs");
+    assert!(
+        status.success(),
+        "building the module failed, is the wasm32-unknown-unknown target installed?"
+    );
+    std::fs::read(
+        target_dir.join("wasm32-unknown-unknown/release/examples/compute_root_hash_wasm.wasm"),
+    )
+    .unwrap()
+}
+
+/// Fuel of every call, more than any call consumes.
+const FUEL: u64 = u64::MAX / 2;
+
+/// Instance of the module, with fuel metering.
+struct Module {
+    store: Store<()>,
+    instance: Instance,
+    memory: Memory,
+}
+
+impl Module {
+    fn new(wasm: &[u8]) -> Self {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = wasmi::Module::new(&engine, wasm).unwrap();
+        // the contract entrypoints linked in import the host functions, the bench calls none of them.
+        let mut linker = Linker::new(&engine);
+        for import in module.imports() {
+            if let ExternType::Func(ty) = import.ty() {
+                linker
+                    .func_new(import.module(), import.name(), ty.clone(), |_, _, _| {
+                        Err(wasmi::Error::new("host functions are not benchmarked"))
+                    })
+                    .unwrap();
+            }
+        }
+        let mut store = Store::new(&engine, ());
+        let instance = linker.instantiate_and_start(&mut store, &module).unwrap();
+        let memory = instance.get_memory(&store, "memory").unwrap();
+        Module {
+            store,
+            instance,
+            memory,
+        }
+    }
+
+    /// Writes the bytes to a buffer of the module, returns its pointer.
+    fn write(&mut self, bytes: &[u8]) -> u32 {
+        let allocate = self
+            .instance
+            .get_typed_func::<u32, u32>(&self.store, "allocate_ptr")
+            .unwrap();
+        self.store.set_fuel(FUEL).unwrap();
+        let ptr = allocate
+            .call(&mut self.store, bytes.len().max(1) as u32)
+            .unwrap();
+        self.memory
+            .write(&mut self.store, ptr as usize, bytes)
+            .unwrap();
+        ptr
+    }
+
+    /// Calls the verification export with the proof, returns the instructions it executed.
+    fn instructions(
+        &mut self,
+        export: &str,
+        key: u64,
+        num_leaves: u64,
+        proof: (u32, u32, u32, u32),
+    ) -> u64 {
+        let verify = self
+            .instance
+            .get_typed_func::<(u64, u64, u32, u32, u32, u32), i32>(&self.store, export)
+            .unwrap();
+        let (leaf, side_nodes, num_side_nodes, root) = proof;
+        self.store.set_fuel(FUEL).unwrap();
+        let valid = verify
+            .call(
+                &mut self.store,
+                (key, num_leaves, leaf, side_nodes, num_side_nodes, root),
+            )
+            .unwrap();
+        assert_eq!(valid, 1, "{export} rejected the proof of key {key}");
+        FUEL - self.store.get_fuel().unwrap()
+    }
+}
+
+fn main() {
+    let mut module = Module::new(&build_module());
+    println!("| leaves | legacy instructions | instructions | saved |");
+    println!("|--------|---------------------|--------------|-------|");
+    // a data commitment of DATA_COMMITMENT_MAX tuples, and larger trees.
+    for num_leaves in [1_000u64, 65_536, 1_000_003] {
+        let keys: Vec<u64> = (0..64).map(|i| i * num_leaves / 64).collect();
+        let tree = MerkleTree::from_leaf_hashes(
+            (0..num_leaves)
+                .map(|i| leaf_digest(Bytes::from(i.to_be_bytes().to_vec())))
+                .collect(),
+        );
+        let root = tree.root();
+        let root_ptr = module.write(root.as_slice());
+
+        let (mut legacy, mut current) = (0, 0);
+        for &key in &keys {
+            let leaf = leaf_digest(Bytes::from(key.to_be_bytes().to_vec()));
+            let side_nodes = tree.prove(key as u32).unwrap().sideNodes;
+            let side_node_bytes: Vec<u8> = side_nodes.iter().flat_map(|node| node.0).collect();
+            let proof = (
+                module.write(leaf.as_slice()),
+                module.write(&side_node_bytes),
+                side_nodes.len() as u32,
+                root_ptr,
+            );
+            legacy += module.instructions("legacy_verify_root_hash", key, num_leaves, proof);
+            current += module.instructions("verify_root_hash", key, num_leaves, proof);
+        }
+        let (legacy, current) = (legacy / keys.len() as u64, current / keys.len() as u64);
+        println!(
+            "| {num_leaves} | {legacy} | {current} | {} |",
+            legacy - current
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Wasm module of `binary_merkle_tree::compute_root_hash` and of the recursive implementation it
+//! replaced, which did its index math in `U256` and cloned the side nodes at every level.
+//! The `compute_root_hash` bench counts the instructions each executes.
+use blobstream_contracts_rust::binary_merkle_tree::{compute_root_hash, node_digest};
+use seq_wasm_sdk::{FixedBytes, U256};
+
+pub use seq_wasm_sdk::allocator::{allocate, deallocate}; // re-export
+
+/// Returns the leaf hash and the side nodes written by the bench.
+///
+/// # Safety
+///
+/// `leaf` must point to 32 bytes and `side_nodes` to `num_side_nodes` times 32 bytes.
+unsafe fn read_proof(
+    leaf: *const u8,
+    side_nodes: *const u8,
+    num_side_nodes: u32,
+) -> (FixedBytes<32>, Vec<FixedBytes<32>>) {
+    let leaf = FixedBytes::from_slice(std::slice::from_raw_parts(leaf, 32));
+    let side_nodes = std::slice::from_raw_parts(side_nodes, num_side_nodes as usize * 32)
+        .chunks_exact(32)
+        .map(FixedBytes::from_slice)
+        .collect();
+    (leaf, side_nodes)
+}
+
+/// Returns true if `compute_root_hash` of the proof is the root.
+///
+/// # Safety
+///
+/// `leaf` and `root` must point to 32 bytes and `side_nodes` to `num_side_nodes` times 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn verify_root_hash(
+    key: u64,
+    num_leaves: u64,
+    leaf: *const u8,
+    side_nodes: *const u8,
+    num_side_nodes: u32,
+    root: *const u8,
+) -> bool {
+    let (leaf, side_nodes) = read_proof(leaf, side_nodes, num_side_nodes);
+    let root = std::slice::from_raw_parts(root, 32);
+    compute_root_hash(key, num_leaves, leaf, &side_nodes).is_ok_and(|hash| hash == root)
+}
+
+/// Returns true if `legacy_compute_root_hash` of the proof is the root.
+///
+/// # Safety
+///
+/// `leaf` and `root` must point to 32 bytes and `side_nodes` to `num_side_nodes` times 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn legacy_verify_root_hash(
+    key: u64,
+    num_leaves: u64,
+    leaf: *const u8,
+    side_nodes: *const u8,
+    num_side_nodes: u32,
+    root: *const u8,
+) -> bool {
+    let (leaf, side_nodes) = read_proof(leaf, side_nodes, num_side_nodes);
+    let root = std::slice::from_raw_parts(root, 32);
+    let (hash, is_error) =
+        legacy_compute_root_hash(U256::from(key), U256::from(num_leaves), leaf, side_nodes);
+    !is_error && hash == root
+}
+
+/// The recursive implementation before the rewrite.
+pub fn legacy_compute_root_hash(
+    key: U256,
+    num_leaves: U256,
+    leaf_hash: FixedBytes<32>,
+    side_nodes: Vec<FixedBytes<32>>,
+) -> (FixedBytes<32>, bool) {
+    let zero = U256::from(0);
+    let one = U256::from(1);
+    if num_leaves == zero {
+        return (leaf_hash, true);
+    }
+    if num_leaves == one {
+        if side_nodes.len() > 1
+            || (side_nodes.len() == 1 && side_nodes[0] != FixedBytes::<32>::new([0; 32]))
+        {
+            return (leaf_hash, true);
+        }
+        return (leaf_hash, false);
+    }
+    if side_nodes.is_empty() {
+        return (leaf_hash, true);
+    }
+    let num_left = legacy_get_split_point(num_leaves);
+    let side_nodes_left = &side_nodes[..side_nodes.len() - 1];
+    if key < num_left {
+        let (left_hash, is_error) =
+            legacy_compute_root_hash(key, num_left, leaf_hash, side_nodes_left.to_vec());
+        if is_error {
+            return (leaf_hash, is_error);
+        }
+        return (
+            node_digest(left_hash, side_nodes[side_nodes.len() - 1]),
+            false,
+        );
+    }
+    let (right_hash, is_error) = legacy_compute_root_hash(
+        key - num_left,
+        num_leaves - num_left,
+        leaf_hash,
+        side_nodes_left.to_vec(),
+    );
+    if is_error {
+        return (leaf_hash, is_error);
+    }
+    (
+        node_digest(side_nodes[side_nodes.len() - 1], right_hash),
+        false,
+    )
+}
+
+fn legacy_get_split_point(x: U256) -> U256 {
+    let one = U256::from(1);
+    let mut bit_len = U256::from(0);
+    let mut y = x;
+    while y != U256::from(0) {
+        bit_len += one;
+        y >>= one;
+    }
+    let mut k = one << (bit_len - one);
+    if k == x {
+        k >>= one;
+    }
+    k
+}
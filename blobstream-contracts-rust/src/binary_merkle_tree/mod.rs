@@ -1,160 +1,476 @@
-use crate::{BinaryMerkleProof, LeafDigestPacker, NodeDigestPacker};
+use crate::error::{
+    Error, InvalidLeafKeys, InvalidLeafRange, InvalidMerkleProof, KeyNotInTree, LeavesCountMismatch,
+};
+use crate::{
+    BinaryMerkleMultiproof, BinaryMerkleProof, BinaryMerkleRangeProof, LeafDigestPacker,
+    NodeDigestPacker,
+};
 use crate::{Bytes, FixedBytes, FromHex, SolValue, U256};
 use sha2::Digest;
 
+/// Largest number of leaves of a tree with a proof.
+pub const MAX_NUM_LEAVES: u64 = 1 << 32;
+
+/// Verifies the merkle proof of the data against the root.
+/// Returns false if the proof is malformed or for a different root.
 pub fn verify(root: FixedBytes<32>, proof: BinaryMerkleProof, data: Bytes) -> bool {
     let one = U256::from(1);
-    if proof.numLeaves <= one {
-        if proof.sideNodes.len() != 0 {
-            if !(proof.sideNodes.len() == 1
-                && proof.sideNodes == vec![FixedBytes::<32>::new([0; 32])])
-            {
-                return false; // @todo should we include error codes?
-            }
-        }
+    // a single zero side node is accepted as an empty proof.
+    let is_zero_side_node =
+        proof.sideNodes.len() == 1 && proof.sideNodes == vec![FixedBytes::<32>::new([0; 32])];
+    if proof.numLeaves <= one && !proof.sideNodes.is_empty() && !is_zero_side_node {
+        return false;
     }
-    // lol fixed_bytes of side nodes, need to be validated in a different way
-    if U256::from(proof.sideNodes.len()) != path_length_from_key(proof.key, proof.numLeaves) {
-        if !(proof.sideNodes.len() == 1 && proof.sideNodes == vec![FixedBytes::<32>::new([0; 32])])
-        {
-            return false;
-        }
+    // the index math is native, trees of more leaves are rejected.
+    if proof.numLeaves > U256::from(MAX_NUM_LEAVES) {
+        return false;
+    }
+    let num_leaves = proof.numLeaves.to::<u64>();
+    // a key that does not fit is not in the tree, saturating keeps it after the last leaf.
+    let key = u64::try_from(proof.key).unwrap_or(u64::MAX);
+    if proof.sideNodes.len() as u64 != path_length_from_key(key, num_leaves) && !is_zero_side_node {
+        return false;
     }
     // check if key is in tree
-    if proof.key >= proof.numLeaves {
+    if key >= num_leaves {
         return false;
     }
     let digest = leaf_digest(data);
 
     // Null proof is valid if num_leaves = 1, if so just veify hash(data) is root
-    if proof.sideNodes.len() == 0 {
-        if proof.numLeaves == one {
-            return root == digest;
+    if proof.sideNodes.is_empty() {
+        return num_leaves == 1 && root == digest;
+    }
+    compute_root_hash(key, num_leaves, digest, &proof.sideNodes).is_ok_and(|hash| hash == root)
+}
+
+/// Verifies the RFC 6962 range proof of the data, the leaves beginKey..endKey of the tree with `root`.
+/// The side nodes are the roots of the subtrees outside of the range, from left to right.
+/// Returns an error if the proof is malformed, and false if the proof is for a different root.
+pub fn verify_range(
+    root: FixedBytes<32>,
+    proof: &BinaryMerkleRangeProof,
+    data: &[Bytes],
+) -> Result<bool, Error> {
+    let leaves: Vec<FixedBytes<32>> = data.iter().map(|leaf| leaf_digest(leaf.clone())).collect();
+    verify_range_hashes(root, proof, &leaves)
+}
+
+/// Verifies the range proof of the leaf hashes, the leaves beginKey..endKey of the tree with `root`.
+pub fn verify_range_hashes(
+    root: FixedBytes<32>,
+    proof: &BinaryMerkleRangeProof,
+    leaves: &[FixedBytes<32>],
+) -> Result<bool, Error> {
+    let invalid_range = || {
+        Error::InvalidLeafRange(InvalidLeafRange {
+            beginKey: proof.beginKey,
+            endKey: proof.endKey,
+        })
+    };
+    let begin = u32::try_from(proof.beginKey).map_err(|_| invalid_range())? as u64;
+    let end = u32::try_from(proof.endKey).map_err(|_| invalid_range())? as u64;
+    if begin >= end {
+        return Err(invalid_range());
+    }
+    if leaves.len() as u64 != end - begin {
+        return Err(Error::LeavesCountMismatch(LeavesCountMismatch {
+            expected: U256::from(end - begin),
+            found: U256::from(leaves.len()),
+        }));
+    }
+
+    let hash = compute_range_root(begin, end, leaves, &proof.sideNodes, node_digest_ref)
+        .ok_or(Error::InvalidMerkleProof(InvalidMerkleProof {}))?;
+    Ok(hash == root)
+}
+
+/// Verifies the multiproof of the data, the leaves at the keys of the tree with `root`, in order of the keys.
+/// The side nodes are the roots of the subtrees without a proven leaf, from left to right, so the
+/// multiproof of consecutive keys has the side nodes of their range proof.
+/// Returns an error if the proof is malformed, and false if the proof is for a different root.
+pub fn verify_multi(
+    root: FixedBytes<32>,
+    proof: &BinaryMerkleMultiproof,
+    data: &[Bytes],
+) -> Result<bool, Error> {
+    let leaves: Vec<FixedBytes<32>> = data.iter().map(|leaf| leaf_digest(leaf.clone())).collect();
+    verify_multi_hashes(root, proof, &leaves)
+}
+
+/// Verifies the multiproof of the leaf hashes, the leaves at the keys of the tree with `root`.
+pub fn verify_multi_hashes(
+    root: FixedBytes<32>,
+    proof: &BinaryMerkleMultiproof,
+    leaves: &[FixedBytes<32>],
+) -> Result<bool, Error> {
+    let invalid_keys = || Error::InvalidLeafKeys(InvalidLeafKeys {});
+    let keys = proof
+        .keys
+        .iter()
+        .map(|key| u32::try_from(*key).map(u64::from))
+        .collect::<Result<Vec<u64>, _>>()
+        .map_err(|_| invalid_keys())?;
+    if keys.is_empty() || keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(invalid_keys());
+    }
+    if leaves.len() != keys.len() {
+        return Err(Error::LeavesCountMismatch(LeavesCountMismatch {
+            expected: U256::from(keys.len()),
+            found: U256::from(leaves.len()),
+        }));
+    }
+
+    let hash = compute_multi_root(&keys, leaves, &proof.sideNodes, node_digest_ref)
+        .ok_or(Error::InvalidMerkleProof(InvalidMerkleProof {}))?;
+    Ok(hash == root)
+}
+
+/// Returns the root of the tree with the leaves begin..end and the side nodes of their range proof,
+/// the parent of two nodes is `hash(left, right)`. The side nodes are the roots of the subtrees
+/// outside of the range from left to right, `leaves` must hold end - begin nodes.
+/// Returns None if the side nodes are not the ones of the range.
+pub fn compute_range_root<T: Clone>(
+    begin: u64,
+    end: u64,
+    leaves: &[T],
+    side_nodes: &[T],
+    hash: impl Fn(&T, &T) -> T,
+) -> Option<T> {
+    let contains = |start: u64, stop: u64| start < end && begin < stop;
+    compute_proof_root(end, contains, leaves, side_nodes, hash)
+}
+
+/// Returns the root of the tree with the leaves at the increasing keys and the side nodes of their
+/// multiproof, the parent of two nodes is `hash(left, right)`. The side nodes are the roots of the
+/// subtrees without a key from left to right, `leaves` must hold a node per key.
+/// Returns None if the side nodes are not the ones of the keys.
+pub fn compute_multi_root<T: Clone>(
+    keys: &[u64],
+    leaves: &[T],
+    side_nodes: &[T],
+    hash: impl Fn(&T, &T) -> T,
+) -> Option<T> {
+    let end = keys.last().map_or(0, |key| key + 1);
+    let contains = |start: u64, stop: u64| {
+        let i = keys.partition_point(|key| *key < start);
+        i < keys.len() && keys[i] < stop
+    };
+    compute_proof_root(end, contains, leaves, side_nodes, hash)
+}
+
+/// Returns the root of the tree with the proven leaves, all before `end`, and the side nodes.
+fn compute_proof_root<T: Clone>(
+    end: u64,
+    contains: impl Fn(u64, u64) -> bool,
+    leaves: &[T],
+    side_nodes: &[T],
+    hash: impl Fn(&T, &T) -> T,
+) -> Option<T> {
+    let mut proof = ProofNodes {
+        contains,
+        leaves: leaves.iter(),
+        side_nodes: side_nodes.iter(),
+        hash,
+    };
+    // the smallest power of two subtree containing the proven leaves is the left most subtree of
+    // its size, the side nodes left after computing its root are its right siblings up to the root.
+    let subtree_size = (get_split_point(end) * 2).max(1);
+    let mut node = proof.compute_root(0, subtree_size).ok()??;
+    for side_node in proof.side_nodes {
+        node = (proof.hash)(&node, side_node);
+    }
+    Some(node)
+}
+
+/// Proven leaves and side nodes of a range proof or multiproof, consumed from left to right.
+struct ProofNodes<'a, T, H, C> {
+    // returns true if a leaf of start..end is proven.
+    contains: C,
+    leaves: std::slice::Iter<'a, T>,
+    side_nodes: std::slice::Iter<'a, T>,
+    hash: H,
+}
+
+impl<T: Clone, H: Fn(&T, &T) -> T, C: Fn(u64, u64) -> bool> ProofNodes<'_, T, H, C> {
+    /// Returns the root of the subtree of the leaves start..end, None if the subtree is not in the tree.
+    /// Returns an error if a subtree is in the tree while the one on its left is not.
+    fn compute_root(&mut self, start: u64, end: u64) -> Result<Option<T>, ()> {
+        let contains = (self.contains)(start, end);
+        if end - start == 1 && contains {
+            return Ok(self.leaves.next().cloned());
+        }
+        // a subtree without a proven leaf is a side node, none are left if it is not in the tree.
+        if end - start == 1 || !contains {
+            return Ok(self.side_nodes.next().cloned());
+        }
+        let k = get_split_point(end - start);
+        let left = self.compute_root(start, start + k)?;
+        let right = self.compute_root(start + k, end)?;
+        match (left, right) {
+            (Some(left), Some(right)) => Ok(Some((self.hash)(&left, &right))),
+            (left, None) => Ok(left),
+            (None, Some(_)) => Err(()),
         }
-        return false;
     }
-    let (hash, is_error) = compute_root_hash(proof.key, proof.numLeaves, digest, proof.sideNodes);
-    if is_error {
-        return false;
+}
+
+/// Binary merkle tree of leaves, to compute its root and proofs with the hashing of `verify`.
+pub struct MerkleTree {
+    leaves: Vec<FixedBytes<32>>,
+}
+
+impl MerkleTree {
+    /// Returns the tree of the leaf data.
+    pub fn new(data: &[Bytes]) -> Self {
+        Self::from_leaf_hashes(data.iter().map(|leaf| leaf_digest(leaf.clone())).collect())
+    }
+
+    /// Returns the tree of the leaf digests.
+    pub fn from_leaf_hashes(leaves: Vec<FixedBytes<32>>) -> Self {
+        Self { leaves }
+    }
+
+    /// Returns the number of leaves.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns true if the tree has no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the root, the sha256 hash of no data if the tree is empty.
+    pub fn root(&self) -> FixedBytes<32> {
+        if self.leaves.is_empty() {
+            return FixedBytes::new(sha2::Sha256::digest([]).into());
+        }
+        subtree_root(&self.leaves, &node_digest_ref)
+    }
+
+    /// Returns the proof of the leaf at `key`, side nodes bottom up.
+    pub fn prove(&self, key: u32) -> Result<BinaryMerkleProof, Error> {
+        if key as usize >= self.leaves.len() {
+            return Err(Error::KeyNotInTree(KeyNotInTree {
+                key: U256::from(key),
+                numLeaves: U256::from(self.leaves.len()),
+            }));
+        }
+        let mut side_nodes = Vec::new();
+        let mut leaves = self.leaves.as_slice();
+        let mut index = key as usize;
+        // descend from the root, the side nodes are collected top down.
+        while leaves.len() > 1 {
+            let k = get_split_point(leaves.len() as u64) as usize;
+            if index < k {
+                side_nodes.push(subtree_root(&leaves[k..], &node_digest_ref));
+                leaves = &leaves[..k];
+            } else {
+                side_nodes.push(subtree_root(&leaves[..k], &node_digest_ref));
+                leaves = &leaves[k..];
+                index -= k;
+            }
+        }
+        side_nodes.reverse();
+        Ok(BinaryMerkleProof {
+            sideNodes: side_nodes,
+            key: U256::from(key),
+            numLeaves: U256::from(self.leaves.len()),
+        })
+    }
+
+    /// Returns the range proof of the leaves begin..end, side nodes from left to right.
+    pub fn prove_range(&self, begin: u32, end: u32) -> Result<BinaryMerkleRangeProof, Error> {
+        if begin >= end || end as usize > self.leaves.len() {
+            return Err(Error::InvalidLeafRange(InvalidLeafRange {
+                beginKey: U256::from(begin),
+                endKey: U256::from(end),
+            }));
+        }
+        let range = begin as usize..end as usize;
+        Ok(BinaryMerkleRangeProof {
+            sideNodes: range_side_nodes(&self.leaves, range, &node_digest_ref),
+            beginKey: U256::from(begin),
+            endKey: U256::from(end),
+        })
     }
 
-    hash == root
+    /// Returns the multiproof of the leaves at the increasing keys, side nodes from left to right.
+    pub fn prove_multi(&self, keys: &[u32]) -> Result<BinaryMerkleMultiproof, Error> {
+        if keys.is_empty() || keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Error::InvalidLeafKeys(InvalidLeafKeys {}));
+        }
+        let last = keys[keys.len() - 1];
+        if last as usize >= self.leaves.len() {
+            return Err(Error::KeyNotInTree(KeyNotInTree {
+                key: U256::from(last),
+                numLeaves: U256::from(self.leaves.len()),
+            }));
+        }
+        let keys: Vec<usize> = keys.iter().map(|key| *key as usize).collect();
+        Ok(BinaryMerkleMultiproof {
+            sideNodes: multi_side_nodes(&self.leaves, &keys, &node_digest_ref),
+            keys: keys.into_iter().map(U256::from).collect(),
+        })
+    }
 }
 
-pub fn path_length_from_key(key: U256, num_leaves: U256) -> U256 {
-    let max_height = U256::from(256);
-    let one = U256::from(1);
-    if num_leaves <= one {
-        return U256::from(0);
-    }
-    let path_length = max_height - get_starting_bit(num_leaves);
-    let num_leaves_left_sub_tree = one << (path_length - one);
-    if key <= num_leaves_left_sub_tree - one {
-        path_length
-    } else if num_leaves_left_sub_tree == one {
-        one
-    } else {
-        one + path_length_from_key(
-            key - num_leaves_left_sub_tree,
-            num_leaves - num_leaves_left_sub_tree,
-        )
+/// Returns the root of the non empty leaves, the parent of two nodes is `hash(left, right)`.
+pub fn subtree_root<T: Clone>(leaves: &[T], hash: &impl Fn(&T, &T) -> T) -> T {
+    if leaves.len() == 1 {
+        return leaves[0].clone();
     }
+    let k = get_split_point(leaves.len() as u64) as usize;
+    hash(
+        &subtree_root(&leaves[..k], hash),
+        &subtree_root(&leaves[k..], hash),
+    )
 }
 
-pub fn get_starting_bit(num_leaves: U256) -> U256 {
-    let max_height = U256::from(256);
-    let one = U256::from(1);
-    let mut starting_bit = U256::from(0);
-    while (one << starting_bit) < num_leaves {
-        starting_bit += one;
+/// Returns the side nodes of the range proof of the leaves in `range`, the roots of the subtrees
+/// outside of the range from left to right. The parent of two nodes is `hash(left, right)`.
+pub fn range_side_nodes<T: Clone>(
+    leaves: &[T],
+    range: std::ops::Range<usize>,
+    hash: &impl Fn(&T, &T) -> T,
+) -> Vec<T> {
+    let contains = |start: usize, end: usize| start < range.end && range.start < end;
+    let mut side_nodes = Vec::new();
+    collect_side_nodes(leaves, 0, &contains, hash, &mut side_nodes);
+    side_nodes
+}
+
+/// Returns the side nodes of the multiproof of the leaves at the increasing keys, the roots of the
+/// subtrees without a key from left to right. The parent of two nodes is `hash(left, right)`.
+pub fn multi_side_nodes<T: Clone>(
+    leaves: &[T],
+    keys: &[usize],
+    hash: &impl Fn(&T, &T) -> T,
+) -> Vec<T> {
+    let contains = |start: usize, end: usize| {
+        let i = keys.partition_point(|key| *key < start);
+        i < keys.len() && keys[i] < end
+    };
+    let mut side_nodes = Vec::new();
+    collect_side_nodes(leaves, 0, &contains, hash, &mut side_nodes);
+    side_nodes
+}
+
+/// Appends the roots of the subtrees without a proven leaf from left to right, `leaves` start at
+/// `offset` and `contains(start, end)` is true if a leaf of start..end is proven.
+fn collect_side_nodes<T: Clone>(
+    leaves: &[T],
+    offset: usize,
+    contains: &impl Fn(usize, usize) -> bool,
+    hash: &impl Fn(&T, &T) -> T,
+    side_nodes: &mut Vec<T>,
+) {
+    if !contains(offset, offset + leaves.len()) {
+        side_nodes.push(subtree_root(leaves, hash));
+        return;
+    }
+    if leaves.len() == 1 {
+        return;
+    }
+    let k = get_split_point(leaves.len() as u64) as usize;
+    collect_side_nodes(&leaves[..k], offset, contains, hash, side_nodes);
+    collect_side_nodes(&leaves[k..], offset + k, contains, hash, side_nodes);
+}
+
+/// Returns the parent of the nodes, `node_digest` over references for the generic tree functions.
+fn node_digest_ref(left: &FixedBytes<32>, right: &FixedBytes<32>) -> FixedBytes<32> {
+    node_digest(*left, *right)
+}
+
+/// Returns the number of side nodes of the proof of the leaf at `key`.
+pub fn path_length_from_key(key: u64, num_leaves: u64) -> u64 {
+    let mut key = key;
+    let mut num_leaves = num_leaves;
+    let mut path_length = 0;
+    while num_leaves > 1 {
+        // the left subtree is the largest power of 2 less than the number of leaves.
+        let height = (u64::BITS - (num_leaves - 1).leading_zeros()) as u64;
+        let num_leaves_left_sub_tree = 1 << (height - 1);
+        if key < num_leaves_left_sub_tree {
+            return path_length + height;
+        }
+        path_length += 1;
+        key -= num_leaves_left_sub_tree;
+        num_leaves -= num_leaves_left_sub_tree;
     }
-    max_height - starting_bit
+    path_length
 }
 
 pub fn leaf_digest(data: Bytes) -> FixedBytes<32> {
     let leaf_prefix = FixedBytes::<1>::from_hex("00").unwrap();
-    let leaf_digest = LeafDigestPacker {
-        leaf_prefix,
-        data: data,
-    };
+    let leaf_digest = LeafDigestPacker { leaf_prefix, data };
     let digest = sha2::Sha256::digest(leaf_digest.abi_encode_packed()).to_vec();
     FixedBytes::from_slice(&digest)
 }
 
+/// Returns the root of the tree with the leaf hash at `key`, side nodes bottom up.
+/// Returns an error if the side nodes are not the path of the key.
 pub fn compute_root_hash(
-    key: U256,
-    num_leaves: U256,
+    key: u64,
+    num_leaves: u64,
     leaf_hash: FixedBytes<32>,
-    side_nodes: Vec<FixedBytes<32>>,
-) -> (FixedBytes<32>, bool /* is_error */) {
-    let zero = U256::from(0);
-    let one = U256::from(1);
-    if num_leaves == zero {
-        return (leaf_hash, true);
+    side_nodes: &[FixedBytes<32>],
+) -> Result<FixedBytes<32>, Error> {
+    let invalid = || Error::InvalidMerkleProof(InvalidMerkleProof {});
+    if num_leaves == 0 {
+        return Err(invalid());
     }
-    if num_leaves == one {
-        if side_nodes.len() != 0 {
-            if !(side_nodes.len() == 1 && side_nodes == vec![FixedBytes::<32>::new([0; 32])]) {
-                return (leaf_hash, true);
-            }
+
+    // walk down from the root to the leaf, bit `depth` of `is_right` is set if the path goes right.
+    let mut key = key;
+    let mut num_leaves = num_leaves;
+    let mut depth = 0;
+    let mut is_right = 0u64;
+    while num_leaves > 1 {
+        if depth == side_nodes.len() {
+            return Err(invalid());
         }
-        return (leaf_hash, false);
-    }
-    if side_nodes.len() == 0 {
-        return (leaf_hash, true);
-    }
-    let num_left = get_split_point(num_leaves);
-    let side_nodes_left = &side_nodes[..side_nodes.len() - 1];
-    if key < num_left {
-        let (left_hash, is_error) =
-            compute_root_hash(key, num_left, leaf_hash, side_nodes_left.to_vec());
-        if is_error {
-            return (leaf_hash, is_error);
+        let num_left = get_split_point(num_leaves);
+        if key < num_left {
+            num_leaves = num_left;
+        } else {
+            is_right |= 1 << depth;
+            key -= num_left;
+            num_leaves -= num_left;
         }
-        return (
-            node_digest(left_hash, side_nodes[side_nodes.len() - 1]),
-            false,
-        );
-    }
-    let (right_hash, is_error) = compute_root_hash(
-        key - num_left,
-        num_leaves - num_left,
-        leaf_hash,
-        side_nodes_left.to_vec(),
-    );
-    if is_error {
-        return (leaf_hash, is_error);
-    }
-
-    (
-        node_digest(side_nodes[side_nodes.len() - 1], right_hash),
-        false,
-    )
-}
-
-/// returns largest power of 2 less than x
-pub fn get_split_point(x: U256) -> U256 {
-    let one = U256::from(1);
-    if x < one {
-        panic!(); // TODO: panic or false
+        depth += 1;
     }
-    let bit_len = bits_len(x);
-    let mut k = one << (bit_len - one);
-    if k == x {
-        k >>= one;
+    // side nodes below the leaf, only a single zero side node is accepted.
+    let remaining = &side_nodes[..side_nodes.len() - depth];
+    if remaining.len() > 1 || (remaining.len() == 1 && remaining[0] != FixedBytes::ZERO) {
+        return Err(invalid());
     }
-    k
+
+    // hash up from the leaf, the side node of depth `d` is the `d`th from the end.
+    let mut hash = leaf_hash;
+    for d in (0..depth).rev() {
+        let side_node = side_nodes[side_nodes.len() - 1 - d];
+        hash = if is_right & (1 << d) == 0 {
+            node_digest(hash, side_node)
+        } else {
+            node_digest(side_node, hash)
+        };
+    }
+    Ok(hash)
 }
 
-pub fn bits_len(mut x: U256) -> U256 {
-    let zero = U256::from(0);
-    let one = U256::from(1);
-    let mut count = zero;
-    while x != zero {
-        count += one;
-        x >>= one;
+/// Returns the largest power of 2 less than `x`, 0 if `x` is at most 1.
+pub fn get_split_point(x: u64) -> u64 {
+    if x <= 1 {
+        return 0;
+    }
+    let k = 1 << (63 - x.leading_zeros());
+    if k == x {
+        k >> 1
+    } else {
+        k
     }
-    count
 }
 
 pub fn node_digest(left: FixedBytes<32>, right: FixedBytes<32>) -> FixedBytes<32> {
@@ -0,0 +1,115 @@
+use crate::sol;
+use seq_wasm_sdk::access_control::AccessControlError;
+use seq_wasm_sdk::migration::MigrationError;
+
+sol! {
+    /// Errors a call to the contract reverts with.
+    #[derive(Debug, PartialEq, Eq)]
+    interface IBlobstream {
+        /// The contract is already initialized.
+        error AlreadyInitialized();
+        /// The contract is not initialized yet.
+        error NotInitialized();
+        /// The contract is frozen by the guardian.
+        error ContractFrozen();
+        /// The account does not have the role.
+        error MissingRole(bytes32 role, bytes account);
+        /// No transfer of the role to the account is pending.
+        error NoPendingTransfer(bytes32 role, bytes account);
+        /// The bytes are not a valid address.
+        error InvalidAccount(bytes account);
+        /// The relayer check is enabled and msg_sender is not an approved relayer.
+        error RelayerNotApproved(bytes relayer);
+        /// The state has another layout version than the call expects.
+        error LayoutVersionMismatch(uint32 expected, uint32 found);
+        /// The calldata can not be ABI decoded into the parameters of the function.
+        error InvalidCalldata();
+        /// No header hash is stored for the latest block.
+        error TrustedHeaderNotFound(uint64 latestBlock);
+        /// The trusted block of the proof is not the latest block.
+        error TrustedBlockMismatch(uint64 latestBlock, uint64 trustedBlock);
+        /// The trusted header of the proof is not the header of the latest block.
+        error TrustedHeaderMismatch(bytes32 expected, bytes32 found);
+        /// The target block is not after the latest block.
+        error TargetBlockNotInRange(uint64 latestBlock, uint64 targetBlock);
+        /// The target block is more than `DATA_COMMITMENT_MAX` blocks after the latest block.
+        error TargetBlockTooFar(uint64 targetBlock, uint64 maxBlock);
+        /// No header ranges are submitted.
+        error EmptyBatch();
+        /// The public values can not be decoded as `ProofOutputs`.
+        error InvalidPublicValues();
+        /// The proof was rejected by the verifier.
+        error InvalidProof();
+        /// The proof nonce is zero or after the latest proof nonce.
+        error InvalidProofNonce(uint256 proofNonce);
+        /// The key of the merkle proof is not less than the number of leaves.
+        error KeyNotInTree(uint256 key, uint256 numLeaves);
+        /// The root can not be computed from the merkle proof.
+        error InvalidMerkleProof();
+        /// The leaf range [beginKey, endKey) of the merkle range proof is empty or too large.
+        error InvalidLeafRange(uint256 beginKey, uint256 endKey);
+        /// The keys of the merkle multiproof are empty, not increasing or too large.
+        error InvalidLeafKeys();
+        /// The number of leaves is not the number of leaves proven by the merkle proof.
+        error LeavesCountMismatch(uint256 expected, uint256 found);
+        /// The shares proof has no shares.
+        error EmptySharesProof();
+        /// The number of row proofs is not the number of row roots.
+        error RowProofsCountMismatch(uint256 expected, uint256 found);
+        /// The number of share proofs is not the number of row roots.
+        error ShareProofsCountMismatch(uint256 expected, uint256 found);
+        /// The number of shares is not the number of leaves proven by the share proofs.
+        error SharesCountMismatch(uint256 expected, uint256 found);
+        /// The leaf range [beginKey, endKey) of the namespace merkle proof is empty or too large.
+        error InvalidShareRange(uint256 beginKey, uint256 endKey);
+        /// The root can not be computed from the namespace merkle proof.
+        error InvalidNamespaceMerkleProof();
+    }
+}
+
+/// Reasons a call to the contract fails.
+pub use IBlobstream::IBlobstreamErrors as Error;
+pub use IBlobstream::{
+    AlreadyInitialized, ContractFrozen, EmptyBatch, EmptySharesProof, InvalidAccount,
+    InvalidCalldata, InvalidLeafKeys, InvalidLeafRange, InvalidMerkleProof,
+    InvalidNamespaceMerkleProof, InvalidProof, InvalidProofNonce, InvalidPublicValues,
+    InvalidShareRange, KeyNotInTree, LayoutVersionMismatch, LeavesCountMismatch, MissingRole,
+    NoPendingTransfer, NotInitialized, RelayerNotApproved, RowProofsCountMismatch,
+    ShareProofsCountMismatch, SharesCountMismatch, TargetBlockNotInRange, TargetBlockTooFar,
+    TrustedBlockMismatch, TrustedHeaderMismatch, TrustedHeaderNotFound,
+};
+
+/// Access control errors are declared by the contract with the same signatures.
+impl From<AccessControlError> for Error {
+    fn from(error: AccessControlError) -> Self {
+        match error {
+            AccessControlError::MissingRole(e) => Error::MissingRole(MissingRole {
+                role: e.role,
+                account: e.account,
+            }),
+            AccessControlError::NoPendingTransfer(e) => {
+                Error::NoPendingTransfer(NoPendingTransfer {
+                    role: e.role,
+                    account: e.account,
+                })
+            }
+            AccessControlError::InvalidAccount(e) => {
+                Error::InvalidAccount(InvalidAccount { account: e.account })
+            }
+        }
+    }
+}
+
+/// Migration errors are declared by the contract with the same signatures.
+impl From<MigrationError> for Error {
+    fn from(error: MigrationError) -> Self {
+        match error {
+            MigrationError::LayoutVersionMismatch(e) => {
+                Error::LayoutVersionMismatch(LayoutVersionMismatch {
+                    expected: e.expected,
+                    found: e.found,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::sol;
+
+sol! {
+    /// Emitted when the header of a block is trusted, by a header range commitment or the guardian.
+    #[derive(Debug, PartialEq, Eq)]
+    event HeadUpdate(uint64 blockNumber, bytes32 headerHash);
+
+    /// Emitted when the data commitment for the block range [startBlock, endBlock) is stored.
+    #[derive(Debug, PartialEq, Eq)]
+    event DataCommitmentStored(
+        uint256 proofNonce,
+        uint64 indexed startBlock,
+        uint64 indexed endBlock,
+        bytes32 indexed dataCommitment
+    );
+
+    /// Emitted when the guardian freezes or unfreezes the contract.
+    #[derive(Debug, PartialEq, Eq)]
+    event FreezeUpdated(bool frozen);
+
+    /// Emitted when the guardian updates the program vkey.
+    #[derive(Debug, PartialEq, Eq)]
+    event ProgramVkeyUpdated(bytes programVkeyHash);
+
+    /// Emitted when the guardian approves or removes a relayer.
+    #[derive(Debug, PartialEq, Eq)]
+    event RelayerApprovalUpdated(bytes relayer, bool approved);
+
+    /// Emitted when the guardian enables or disables the check for approved relayers.
+    #[derive(Debug, PartialEq, Eq)]
+    event CheckRelayerUpdated(bool checkRelayer);
+}
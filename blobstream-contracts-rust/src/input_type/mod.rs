@@ -1,4 +1,4 @@
-use crate::{input, slice, sol, FixedBytes, SolType, U256};
+use crate::sol;
 
 sol!(
     struct DataRootTuple{
@@ -10,31 +10,14 @@ sol!(
         uint256 key;
         uint256 numLeaves;
     }
-    struct InitializerInput {
-        uint64  height;
-        bytes32 header;
-        bytes blobstreamProgramVKeyHash;
-        bytes blobstreamProgramVKey;
-    }
-    struct UpdateFreezeInput{
-        bool freeze;
-    }
-    struct UpdateGenesisStateInput{
-        uint64 height;
-        bytes32 header;
-    }
-    struct UpdateProgramVkeyInput{
-        bytes blobstreamProgramVKeyHash;
-        bytes blobstreamProgramVKey;
-    }
-    struct CommitHeaderRangeInput {
-        bytes proof;
-        bytes publicValues;
+    struct BinaryMerkleRangeProof{
+        bytes32[] sideNodes;
+        uint256 beginKey;
+        uint256 endKey;
     }
-    struct VAInput {
-        uint256 tuple_root_nonce;
-        DataRootTuple tuple;
-        BinaryMerkleProof proof;
+    struct BinaryMerkleMultiproof{
+        bytes32[] sideNodes;
+        uint256[] keys;
     }
     struct ProofOutputs {
         bytes32 trustedHeaderHash;
@@ -44,6 +27,44 @@ sol!(
         uint64 targetBlock;
         uint256 validatorBitmap;
     }
+    struct VAInput {
+        uint256 proofNonce;
+        DataRootTuple tuple;
+        BinaryMerkleProof proof;
+    }
+    #[derive(Debug, PartialEq, Eq)]
+    struct Namespace {
+        bytes1 version;
+        bytes28 id;
+    }
+    #[derive(Debug, PartialEq, Eq)]
+    struct NamespaceNode {
+        Namespace min;
+        Namespace max;
+        bytes32 digest;
+    }
+    struct NamespaceMerkleMultiproof {
+        uint256 beginKey;
+        uint256 endKey;
+        NamespaceNode[] sideNodes;
+    }
+    struct AttestationProof {
+        uint256 tupleRootNonce;
+        DataRootTuple tuple;
+        BinaryMerkleProof proof;
+    }
+    struct SharesProof {
+        bytes[] data;
+        NamespaceMerkleMultiproof[] shareProofs;
+        Namespace namespace;
+        NamespaceNode[] rowRoots;
+        BinaryMerkleProof[] rowProofs;
+        AttestationProof attestationProof;
+    }
+    struct CommitHeaderRangeInput {
+        bytes proof;
+        bytes publicValues;
+    }
     struct LeafDigestPacker{
         bytes1 leaf_prefix;
         bytes data;
@@ -58,98 +79,3 @@ sol!(
         bytes32 message;
     }
 );
-
-impl InitializerInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let init_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(init_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> (u64, FixedBytes<32>, Vec<u8>, Vec<u8>) {
-        (
-            self.height,
-            self.header.clone(),
-            self.blobstreamProgramVKeyHash.clone().to_vec(),
-            self.blobstreamProgramVKey.clone().to_vec(),
-        )
-    }
-}
-
-impl UpdateFreezeInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let init_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(init_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> bool {
-        self.freeze
-    }
-}
-
-impl UpdateGenesisStateInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let init_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(init_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> (u64, FixedBytes<32>) {
-        (self.height, self.header.clone())
-    }
-}
-
-impl UpdateProgramVkeyInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let init_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(init_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> (Vec<u8>, Vec<u8>) {
-        (
-            self.blobstreamProgramVKeyHash.clone().to_vec(),
-            self.blobstreamProgramVKey.clone().to_vec(),
-        )
-    }
-}
-
-impl VAInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let va_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(va_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> (U256, DataRootTuple, BinaryMerkleProof) {
-        (
-            self.tuple_root_nonce,
-            self.tuple.clone(),
-            self.proof.clone(),
-        )
-    }
-}
-
-impl CommitHeaderRangeInput {
-    pub fn new(ptr: *const u8, len: u32) -> Self {
-        let init_input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
-        Self::abi_decode(init_input, true).unwrap()
-    }
-    pub fn unpack(&self) -> (Vec<u8>, Vec<u8>) {
-        (
-            self.proof.clone().to_vec(),
-            self.publicValues.clone().to_vec(),
-        )
-    }
-    pub fn unpack_po(
-        &self,
-    ) -> (
-        FixedBytes<32>,
-        FixedBytes<32>,
-        FixedBytes<32>,
-        u64,
-        u64,
-        U256,
-    ) {
-        let po = ProofOutputs::abi_decode(&self.publicValues, true).unwrap();
-        (
-            po.trustedHeaderHash,
-            po.targetHeaderHash,
-            po.dataCommitment,
-            po.trustedBlock,
-            po.targetBlock,
-            po.validatorBitmap,
-        )
-    }
-}
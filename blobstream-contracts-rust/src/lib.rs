@@ -1,244 +1,612 @@
-#![cfg_attr(debug_assertions, allow(dead_code, unused_imports, unused_variables))]
-
 pub mod binary_merkle_tree;
+pub mod error;
+pub mod events;
 pub mod input_type;
+pub mod nmt;
 
 // crate imports.
+use error::*;
+use events::{
+    CheckRelayerUpdated, DataCommitmentStored, FreezeUpdated, HeadUpdate, ProgramVkeyUpdated,
+    RelayerApprovalUpdated,
+};
 use input_type::{
-    BinaryMerkleProof, CommitHeaderRangeInput, InitializerInput, LeafDigestPacker,
-    NodeDigestPacker, UpdateFreezeInput, UpdateGenesisStateInput, UpdateProgramVkeyInput, VAInput,
+    BinaryMerkleMultiproof, BinaryMerkleProof, BinaryMerkleRangeProof, CommitHeaderRangeInput,
+    DataRootTuple, LeafDigestPacker, NodeDigestPacker, ProofOutputs, SharesProof, VAInput,
 };
 
+// std imports.
+use std::collections::BTreeMap;
+
 // seq wasm sdk imports.
+use seq_wasm_sdk::access_control::{self, Role, ADMIN, GUARDIAN};
 pub use seq_wasm_sdk::allocator::*;
-use seq_wasm_sdk::{precompiles, state, utils::TxContext};
-use seq_wasm_sdk::{slice, sol, Bytes, FixedBytes, FromHex, SolType, SolValue, U256};
-use seq_wasm_sdk_macros::{input, public};
-
-// get state variables enum from program vm.
-const STATIC_ISINITIALIZED: u32 = 0;
-const STATIC_FROZEN: u32 = 1;
-const STATIC_GUARDIAN: u32 = 2;
-const STATIC_LATESTBLOCK: u32 = 3;
-const STATIC_STATE_PROOFNONCE: u32 = 4;
-const STATIC_BLOBSTREAM_PROGRAM_VKEY_HASH: u32 = 5; // hash of vk produced for blobstream ELF.
-const STATIC_BLOBSTREAM_PROGRAM_VKEY: u32 = 6; // actual verification key.
-
-// ids for storing dynamic variables.
-const MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID: u32 = 1;
-const MAPPING_STATE_DATA_COMMITMENTS_ID: u32 = 2;
+use seq_wasm_sdk::events::Event;
+use seq_wasm_sdk::migration;
+use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+use seq_wasm_sdk::{precompiles, types::Address, utils::TxContext};
+use seq_wasm_sdk::{sol, Bytes, FixedBytes, FromHex, SolValue, U256};
+use seq_wasm_sdk_macros::{public, storage};
+
+// state layout of the contract, slots and mapping ids are assigned in order of declaration.
+#[storage]
+struct Blobstream {
+    is_initialized: StorageValue<bool>,
+    frozen: StorageValue<bool>,
+    /// guardian of layout version 0, moved to the `access_control` roles by `migrate_roles`.
+    legacy_guardian: StorageValue<Address>,
+    latest_block: StorageValue<u64>,
+    state_proof_nonce: StorageValue<U256>,
+    /// hash of vk produced for blobstream ELF.
+    program_vkey_hash: StorageValue<Vec<u8>>,
+    /// actual verification key.
+    program_vkey: StorageValue<Vec<u8>>,
+    header_hashes: StorageMap<u64, FixedBytes<32>>,
+    data_commitments: StorageMap<U256, FixedBytes<32>>,
+    /// when true, only approved relayers can submit proofs.
+    check_relayer: StorageValue<bool>,
+    approved_relayers: StorageMap<Address, bool>,
+}
 
 // CONSTANT VARIABLES
 const DATA_COMMITMENT_MAX: u64 = 1_000;
+/// Layout version of the state written by this code, see `migrate_roles`.
+const LAYOUT_VERSION: u32 = 1;
 
 #[public]
 /// This function initializes the contract with the initial state variables.
 /// Can only be called once, mostly during the contract deployment.
-pub fn initializer() {
+pub fn initializer(
+    height: u64,
+    header: FixedBytes<32>,
+    blobstream_program_vkey_hash: Bytes,
+    blobstream_program_vkey: Bytes,
+) -> Result<(), Error> {
     if is_initialized() {
         // contract already initialized
-        return false;
+        return Err(Error::AlreadyInitialized(AlreadyInitialized {}));
     }
 
-    // Decode msg_sender from tx_context and inputs from IntializerInput.
-    let (height, header, blobstream_program_vkey_hash, blobstream_program_vkey) =
-        InitializerInput::new(ptr, len).unpack();
-
     // Store the initial state variables and set contract as initialized.
-    state::store_u64(STATIC_LATESTBLOCK, height);
-    state::store_mapping_u64_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, height, header);
-    state::store_u256(STATIC_STATE_PROOFNONCE, U256::from(1));
-    state::store_address(STATIC_GUARDIAN, &msg_sender);
-    state::store_vec(
-        STATIC_BLOBSTREAM_PROGRAM_VKEY_HASH,
-        &blobstream_program_vkey_hash,
-    );
-    state::store_vec(STATIC_BLOBSTREAM_PROGRAM_VKEY, &blobstream_program_vkey);
-    state::store_bool(STATIC_ISINITIALIZED, 1);
-
-    // Call executed without any errors, return true.
-    true
+    Blobstream::latest_block().set(height);
+    Blobstream::header_hashes().insert(height, header);
+    Blobstream::state_proof_nonce().set(U256::from(1));
+    access_control::grant_role_unchecked(ADMIN, &msg_sender);
+    access_control::grant_role_unchecked(GUARDIAN, &msg_sender);
+    Blobstream::program_vkey_hash().set(blobstream_program_vkey_hash.to_vec());
+    Blobstream::program_vkey().set(blobstream_program_vkey.to_vec());
+    migration::set_layout_version(LAYOUT_VERSION);
+    Blobstream::is_initialized().set(true);
+
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the guardian can set the contract to a frozen state.
-pub fn update_freeze() {
-    // Decode msg_sender from tx_context and inputs from UpdateFreezeInput.
-    let freeze = UpdateFreezeInput::new(ptr, len).freeze;
-
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
+pub fn update_freeze(freeze: bool) -> Result<(), Error> {
     // msg_sender is the guardian, update the freeze state variable.
-    state::store_bool(STATIC_FROZEN, freeze as u32);
-
-    // Call executed without any errors, return true.
-    true
+    Blobstream::frozen().set(freeze);
+    FreezeUpdated { frozen: freeze }.emit();
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the gaurdian can update the genesis state of the contract.
-pub fn update_genesis_state() {
-    // Decode msg_sender from tx_context and inputs from UpdateGenesisStateInput.
-
-    let (height, header) = UpdateGenesisStateInput::new(ptr, len).unpack();
+pub fn update_genesis_state(height: u64, header: FixedBytes<32>) -> Result<(), Error> {
+    // msg_sender is the guardian, update the genesis state variables.
+    Blobstream::header_hashes().insert(height, header);
+    Blobstream::latest_block().set(height);
+    HeadUpdate {
+        blockNumber: height,
+        headerHash: header,
+    }
+    .emit();
+    Ok(())
+}
 
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
+#[public]
+#[only_role(GUARDIAN)]
+/// Only the guardian can update the program vkey.
+pub fn update_program_vkey(program_vkey_hash: Bytes, program_vkey: Bytes) -> Result<(), Error> {
+    // msg_sender is the guardian, update program vkey.
+    Blobstream::program_vkey_hash().set(program_vkey_hash.to_vec());
+    Blobstream::program_vkey().set(program_vkey.to_vec());
+    ProgramVkeyUpdated {
+        programVkeyHash: program_vkey_hash,
     }
+    .emit();
+    Ok(())
+}
 
-    // msg_sender is the guardian, update the genesis state variables.
-    state::store_mapping_u64_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, height, header);
-    state::store_u64(STATIC_LATESTBLOCK, height);
+#[public]
+#[only_role(GUARDIAN)]
+/// Only the guardian can approve or remove a relayer.
+pub fn set_relayer_approval(relayer: Bytes, approved: bool) -> Result<(), Error> {
+    let relayer_address = access_control::parse_account(&relayer)?;
+    Blobstream::approved_relayers().insert(relayer_address, approved);
+    RelayerApprovalUpdated { relayer, approved }.emit();
+    Ok(())
+}
 
-    // Call executed without any errors, return true.
-    true
+#[public]
+#[only_role(GUARDIAN)]
+/// Only the guardian can enable or disable the check for approved relayers.
+pub fn set_check_relayer(check_relayer: bool) -> Result<(), Error> {
+    Blobstream::check_relayer().set(check_relayer);
+    CheckRelayerUpdated {
+        checkRelayer: check_relayer,
+    }
+    .emit();
+    Ok(())
 }
 
 #[public]
-/// Only the guardian can update the program vkey.
-pub fn update_program_vkey() {
-    // Decode msg_sender from tx_context and inputs from UpdateProgramVkeyInput.
+#[migrate(from = 0)]
+/// Moves the guardian of layout version 0 to the `ADMIN` and `GUARDIAN` roles, only the guardian can migrate.
+pub fn migrate_roles() -> Result<(), Error> {
+    // roles do not exist before the migration, the guardian is read from its slot of layout version 0.
+    if Blobstream::legacy_guardian().try_get() != Ok(Some(msg_sender.clone())) {
+        return Err(Error::MissingRole(MissingRole {
+            role: GUARDIAN,
+            account: msg_sender.as_bytes().to_vec().into(),
+        }));
+    }
+    access_control::grant_role_unchecked(ADMIN, &msg_sender);
+    access_control::grant_role_unchecked(GUARDIAN, &msg_sender);
+    Blobstream::legacy_guardian().clear();
+    Ok(())
+}
 
-    let (program_vkey_hash, program_vkey) = UpdateProgramVkeyInput::new(ptr, len).unpack();
+#[public]
+/// Starts the transfer of the guardian role to `new_guardian`, which takes effect once it is accepted.
+pub fn transfer_guardian(new_guardian: Bytes) -> Result<(), Error> {
+    let new_guardian = access_control::parse_account(&new_guardian)?;
+    access_control::transfer_role(&msg_sender, GUARDIAN, &new_guardian)?;
+    Ok(())
+}
 
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
+#[public]
+/// Accepts the pending transfer of the guardian role to msg_sender.
+pub fn accept_guardian() -> Result<(), Error> {
+    access_control::accept_role(&msg_sender, GUARDIAN)?;
+    Ok(())
+}
 
-    // msg_sender is the guardian, update program vkey.
-    state::store_vec(STATIC_BLOBSTREAM_PROGRAM_VKEY_HASH, &program_vkey_hash);
-    state::store_vec(STATIC_BLOBSTREAM_PROGRAM_VKEY, &program_vkey);
+#[public]
+/// Only an admin can grant a role.
+pub fn grant_role(role: Role, account: Bytes) -> Result<(), Error> {
+    let account = access_control::parse_account(&account)?;
+    access_control::grant_role(&msg_sender, role, &account)?;
+    Ok(())
+}
+
+#[public]
+/// Only an admin can revoke a role.
+pub fn revoke_role(role: Role, account: Bytes) -> Result<(), Error> {
+    let account = access_control::parse_account(&account)?;
+    access_control::revoke_role(&msg_sender, role, &account)?;
+    Ok(())
+}
 
-    // Call executed without any errors, return true.
-    true
+#[public]
+/// Revokes a role from msg_sender.
+pub fn renounce_role(role: Role) -> Result<(), Error> {
+    access_control::renounce_role(&msg_sender, role)?;
+    Ok(())
 }
 
 #[public]
 /// Commits the new header at targetBlock and the data commitment for the block range [latestBlock, targetBlock).
-pub fn commit_header_range() {
-    // unpack proof and public values from CommitHeaderRangeInput.
-    let (proof, public_values) = CommitHeaderRangeInput::new(ptr, len).unpack();
+pub fn commit_header_range(proof: Bytes, public_values: Bytes) -> Result<(), Error> {
+    // if the relayer check is enabled, only approved relayers can submit proofs.
+    only_approved_relayer(&msg_sender)?;
 
-    let (
-        po_trusted_header_hash,
-        target_header_hash,
-        data_commitment,
-        po_trusted_block,
-        target_block,
-        _,
-    ) = CommitHeaderRangeInput::new(ptr, len).unpack_po();
+    // if contract is frozen or not initialized, the call fails.
+    when_active()?;
 
-    // if contract is frozen or not initialized, return false.
-    if is_frozen() || !is_initialized() {
-        return false;
-    }
+    let head = latest_head()?;
+    let (vkey_hash, vkey) = get_vkey_hash_and_vkey();
+    let po = check_header_range(&head, &proof, &public_values, &vkey_hash, &vkey)?;
 
-    // fetch the latest block and trusted header hash from the state.
-    let latest_block = state::get_u64(STATIC_LATESTBLOCK);
-    let trusted_header =
-        state::get_mapping_u64_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, latest_block);
+    // proof is valid, update state variables.
+    store_header_range(head.block, &po);
+    Ok(())
+}
 
-    // sanity check public values and state values.
-    if trusted_header == FixedBytes::<32>::new([0; 32]) {
-        return false;
-    }
-    if po_trusted_block != latest_block {
-        return false;
+#[public]
+/// Commits consecutive header ranges, each starting at the target block of the previous one.
+/// Nothing is committed unless every range is valid.
+pub fn commit_header_ranges(inputs: Vec<CommitHeaderRangeInput>) -> Result<(), Error> {
+    // if the relayer check is enabled, only approved relayers can submit proofs.
+    only_approved_relayer(&msg_sender)?;
+
+    if inputs.is_empty() {
+        return Err(Error::EmptyBatch(EmptyBatch {}));
     }
-    if trusted_header != po_trusted_header_hash {
-        return false;
+
+    // if contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    // check every range against the target of the previous one before storing any of them.
+    let mut head = latest_head()?;
+    let (vkey_hash, vkey) = get_vkey_hash_and_vkey();
+    let mut ranges = Vec::with_capacity(inputs.len());
+    for input in &inputs {
+        let po = check_header_range(&head, &input.proof, &input.publicValues, &vkey_hash, &vkey)?;
+        let start_block = head.block;
+        head = Head {
+            block: po.targetBlock,
+            header: po.targetHeaderHash,
+        };
+        ranges.push((start_block, po));
     }
-    if target_block <= latest_block || target_block - latest_block > DATA_COMMITMENT_MAX {
-        return false;
+
+    // all proofs are valid, update state variables.
+    for (start_block, po) in &ranges {
+        store_header_range(*start_block, po);
     }
+    Ok(())
+}
 
-    // fetch blobstream program vkey and program vkey hash from the state.
-    let blobstream_program_vkey_hash = state::get_vec(STATIC_BLOBSTREAM_PROGRAM_VKEY_HASH);
-    let blobstream_program_vkey = state::get_vec(STATIC_BLOBSTREAM_PROGRAM_VKEY);
-    // verify sp1 plonk proof.
-    if precompiles::gnark_verify(
-        blobstream_program_vkey_hash,
-        public_values,
-        proof,
-        blobstream_program_vkey,
-    ) {
-        // proof is valid, update state variables.
-        let proof_nonce = state::get_u256(STATIC_STATE_PROOFNONCE);
+#[public]
+/// Returns the latest block with a trusted header.
+pub fn latest_block() -> u64 {
+    Blobstream::latest_block().get()
+}
+
+#[public]
+/// Returns the proof nonce the next data commitment is stored under.
+pub fn state_proof_nonce() -> U256 {
+    Blobstream::state_proof_nonce().get()
+}
 
-        state::store_mapping_u64_bytes32(
-            MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID,
-            target_block,
-            target_header_hash,
-        );
-        state::store_mapping_u256_bytes32(
-            MAPPING_STATE_DATA_COMMITMENTS_ID,
-            proof_nonce,
-            data_commitment,
-        );
-        state::store_u256(STATIC_STATE_PROOFNONCE, proof_nonce + U256::from(1));
-        state::store_u64(STATIC_LATESTBLOCK, target_block);
+#[public]
+/// Returns the data commitment stored under the proof nonce, zero if there is none.
+pub fn data_commitment(proof_nonce: U256) -> FixedBytes<32> {
+    Blobstream::data_commitments().get(proof_nonce)
+}
 
-        // Call executed without any errors, return true.
-        true
-    } else {
-        // proof is invalid, return false.
-        false
-    }
+#[public]
+/// Returns the trusted header hash of the block, zero if there is none.
+pub fn block_height_to_header_hash(height: u64) -> FixedBytes<32> {
+    Blobstream::header_hashes().get(height)
+}
+
+#[public]
+/// Returns true if the contract is frozen.
+pub fn frozen() -> bool {
+    is_frozen()
+}
+
+#[public]
+/// Returns the first account with the `GUARDIAN` role, empty if there is none.
+pub fn guardian() -> Bytes {
+    access_control::role_members(GUARDIAN)
+        .first()
+        .map(|guardian| Bytes::from(guardian.as_bytes().to_vec()))
+        .unwrap_or_default()
+}
+
+#[public]
+/// Returns the accounts with the role, in order of granting.
+pub fn role_members(role: Role) -> Vec<Bytes> {
+    access_control::role_members(role)
+        .iter()
+        .map(|member| Bytes::from(member.as_bytes().to_vec()))
+        .collect()
+}
+
+#[public]
+/// Returns the hash of the program vkey proofs are verified with.
+pub fn program_vkey_hash() -> Bytes {
+    Blobstream::program_vkey_hash().get().into()
 }
 
 #[public]
 /// Verify the attestation for the given proof nonce, tuple, and proof. This is taken from
 /// the existing Blobstream contract and is used to verify the data hash for a specific block
-/// against a posted data commitment.
-pub fn verify_attestation() {
-    // Decode the inputs from the VAInput struct.
-    let (proof_nonce, tuple, proof) = VAInput::new(ptr, len).unpack();
+/// against a posted data commitment. An invalid proof nonce or malformed proof returns false.
+pub fn verify_attestation(
+    proof_nonce: U256,
+    tuple: DataRootTuple,
+    proof: BinaryMerkleProof,
+) -> Result<bool, Error> {
+    // If the contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    // Check if the proof nonce is valid, fetch the data commitment from the state and verify the proof.
+    if only_valid_proof_nonce(proof_nonce).is_err() {
+        return Ok(false);
+    }
+    let root = Blobstream::data_commitments().get(proof_nonce);
+    let data = tuple.abi_encode().into();
+    Ok(binary_merkle_tree::verify(root, proof, data))
+}
 
-    // If the contract is frozen or not initialized, return false.
-    if is_frozen() || !is_initialized() {
-        return false;
+#[public]
+/// Verify the attestation of consecutive tuples for the given proof nonce, with a range proof of
+/// the leaves beginKey..endKey of the data commitment, one tuple per leaf.
+pub fn verify_attestation_range(
+    proof_nonce: U256,
+    tuples: Vec<DataRootTuple>,
+    proof: BinaryMerkleRangeProof,
+) -> Result<bool, Error> {
+    // If the contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    only_valid_proof_nonce(proof_nonce)?;
+    let root = Blobstream::data_commitments().get(proof_nonce);
+    let data: Vec<Bytes> = tuples
+        .iter()
+        .map(|tuple| tuple.abi_encode().into())
+        .collect();
+    binary_merkle_tree::verify_range(root, &proof, &data)
+}
+
+#[public]
+/// Verify the attestation of tuples for the given proof nonce, with a multiproof of the leaves at
+/// its keys of the data commitment, one tuple per key in order of the keys.
+pub fn verify_attestation_multi(
+    proof_nonce: U256,
+    tuples: Vec<DataRootTuple>,
+    proof: BinaryMerkleMultiproof,
+) -> Result<bool, Error> {
+    // If the contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    only_valid_proof_nonce(proof_nonce)?;
+    let root = Blobstream::data_commitments().get(proof_nonce);
+    let data: Vec<Bytes> = tuples
+        .iter()
+        .map(|tuple| tuple.abi_encode().into())
+        .collect();
+    binary_merkle_tree::verify_multi(root, &proof, &data)
+}
+
+#[public]
+/// Verify a batch of attestations, possibly for different proof nonces, as `verify_attestation` does.
+/// Returns a bitmap with bit `i % 8` of byte `i / 8` set if the attestation `i` is valid,
+/// an invalid proof nonce or malformed proof leaves the bit unset instead of failing the call.
+pub fn verify_attestations(inputs: Vec<VAInput>) -> Result<Bytes, Error> {
+    if inputs.is_empty() {
+        return Err(Error::EmptyBatch(EmptyBatch {}));
     }
 
-    // Fetch the state proof nonce and check if the proof nonce is valid.
-    let state_proof_nonce = state::get_u256(STATIC_STATE_PROOFNONCE);
-    if proof_nonce > state_proof_nonce || proof_nonce == U256::from(0) {
-        return false;
+    // If the contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    // the state proof nonce and the data commitment of each nonce are read once per call.
+    let state_proof_nonce = Blobstream::state_proof_nonce().get();
+    let mut data_commitments = BTreeMap::new();
+    let mut bitmap = vec![0u8; inputs.len().div_ceil(8)];
+    for (i, input) in inputs.into_iter().enumerate() {
+        if input.proofNonce > state_proof_nonce || input.proofNonce == U256::from(0) {
+            continue;
+        }
+        let root = *data_commitments
+            .entry(input.proofNonce)
+            .or_insert_with(|| Blobstream::data_commitments().get(input.proofNonce));
+        let data = input.tuple.abi_encode().into();
+        if binary_merkle_tree::verify(root, input.proof, data) {
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
     }
+    Ok(bitmap.into())
+}
 
-    // Fetch the data commitment from the state and verify the proof.
-    let root = state::get_mapping_u256_bytes32(MAPPING_STATE_DATA_COMMITMENTS_ID, proof_nonce);
-    let is_proof_valid = binary_merkle_tree::verify(root, proof, tuple.abi_encode().into());
+#[public]
+/// Verify that the shares are in rows of a Celestia block and that the data root of the block is
+/// attested by a data commitment, chaining shares -> row roots -> data root -> data root tuple.
+/// The shares of each row are the leaves beginKey..endKey of its share proof, in order.
+pub fn verify_shares_to_data_root_tuple(proof: SharesProof) -> Result<bool, Error> {
+    // If the contract is frozen or not initialized, the call fails.
+    when_active()?;
+
+    // check the shape of the proof before hashing anything.
+    if proof.data.is_empty() {
+        return Err(Error::EmptySharesProof(EmptySharesProof {}));
+    }
+    if proof.rowProofs.len() != proof.rowRoots.len() {
+        return Err(Error::RowProofsCountMismatch(RowProofsCountMismatch {
+            expected: U256::from(proof.rowRoots.len()),
+            found: U256::from(proof.rowProofs.len()),
+        }));
+    }
+    if proof.shareProofs.len() != proof.rowRoots.len() {
+        return Err(Error::ShareProofsCountMismatch(ShareProofsCountMismatch {
+            expected: U256::from(proof.rowRoots.len()),
+            found: U256::from(proof.shareProofs.len()),
+        }));
+    }
+    let mut share_counts = Vec::with_capacity(proof.shareProofs.len());
+    for share_proof in &proof.shareProofs {
+        let (begin, end) = nmt::proof_range(share_proof)?;
+        share_counts.push((end - begin) as usize);
+    }
+    let shares: usize = share_counts.iter().sum();
+    if shares != proof.data.len() {
+        return Err(Error::SharesCountMismatch(SharesCountMismatch {
+            expected: U256::from(shares),
+            found: U256::from(proof.data.len()),
+        }));
+    }
 
-    is_proof_valid
+    // the data root tuple is attested by the data commitment of the nonce.
+    let attestation = proof.attestationProof;
+    only_valid_proof_nonce(attestation.tupleRootNonce)?;
+    let data_commitment = Blobstream::data_commitments().get(attestation.tupleRootNonce);
+    let tuple = attestation.tuple.abi_encode().into();
+    if !binary_merkle_tree::verify(data_commitment, attestation.proof, tuple) {
+        return Ok(false);
+    }
+
+    // the row roots are leaves of the data root.
+    let data_root = attestation.tuple.dataRoot;
+    for (row_root, row_proof) in proof.rowRoots.iter().zip(proof.rowProofs) {
+        if !binary_merkle_tree::verify(data_root, row_proof, nmt::node_bytes(row_root)) {
+            return Ok(false);
+        }
+    }
+
+    // the shares are leaves of the row roots.
+    let mut shares = proof.data.as_slice();
+    for ((row_root, share_proof), count) in proof
+        .rowRoots
+        .iter()
+        .zip(&proof.shareProofs)
+        .zip(share_counts)
+    {
+        let (row_shares, rest) = shares.split_at(count);
+        if !nmt::verify_multi(row_root, share_proof, &proof.namespace, row_shares)? {
+            return Ok(false);
+        }
+        shares = rest;
+    }
+    Ok(true)
 }
 
 // Helper functions
 
 /// Returns true if the contract is frozen, false otherwise.
 fn is_frozen() -> bool {
-    if state::get_bool(STATIC_FROZEN) == 1 {
-        true
-    } else {
-        false
-    }
+    Blobstream::frozen().get()
 }
 
 /// Returns true if the contract is initialized, false otherwise.
 fn is_initialized() -> bool {
-    if state::get_bool(STATIC_ISINITIALIZED) == 1 {
-        true
-    } else {
-        false
+    Blobstream::is_initialized().get()
+}
+
+/// Returns an error if the relayer check is enabled and `msg_sender` is not an approved relayer.
+fn only_approved_relayer(msg_sender: &Address) -> Result<(), Error> {
+    if Blobstream::check_relayer().get() && !Blobstream::approved_relayers().get(msg_sender.clone())
+    {
+        return Err(Error::RelayerNotApproved(RelayerNotApproved {
+            relayer: msg_sender.as_bytes().to_vec().into(),
+        }));
     }
+    Ok(())
+}
+
+/// Returns an error if the proof nonce is zero or after the latest proof nonce.
+fn only_valid_proof_nonce(proof_nonce: U256) -> Result<(), Error> {
+    let state_proof_nonce = Blobstream::state_proof_nonce().get();
+    if proof_nonce > state_proof_nonce || proof_nonce == U256::from(0) {
+        return Err(Error::InvalidProofNonce(InvalidProofNonce {
+            proofNonce: proof_nonce,
+        }));
+    }
+    Ok(())
+}
+
+/// Returns an error if the contract is not initialized or frozen.
+fn when_active() -> Result<(), Error> {
+    if !is_initialized() {
+        return Err(Error::NotInitialized(NotInitialized {}));
+    }
+    // state of an older layout has to be migrated first.
+    migration::only_layout_version(LAYOUT_VERSION)?;
+    if is_frozen() {
+        return Err(Error::ContractFrozen(ContractFrozen {}));
+    }
+    Ok(())
+}
+
+/// Latest trusted block and its header, the next header range starts from it.
+struct Head {
+    block: u64,
+    header: FixedBytes<32>,
+}
+
+/// Returns the latest block and its header hash from the state.
+fn latest_head() -> Result<Head, Error> {
+    let block = Blobstream::latest_block().get();
+    match Blobstream::header_hashes().try_get(block) {
+        Ok(Some(header)) => Ok(Head { block, header }),
+        // trusted header not found.
+        _ => Err(Error::TrustedHeaderNotFound(TrustedHeaderNotFound {
+            latestBlock: block,
+        })),
+    }
+}
+
+/// Checks that the header range of the public values starts at `head` and verifies its proof.
+fn check_header_range(
+    head: &Head,
+    proof: &Bytes,
+    public_values: &Bytes,
+    vkey_hash: &[u8],
+    vkey: &[u8],
+) -> Result<ProofOutputs, Error> {
+    // decode the proof outputs from public values.
+    let po = ProofOutputs::abi_decode(public_values, true)
+        .map_err(|_| Error::InvalidPublicValues(InvalidPublicValues {}))?;
+
+    // sanity check public values and state values.
+    if po.trustedBlock != head.block {
+        return Err(Error::TrustedBlockMismatch(TrustedBlockMismatch {
+            latestBlock: head.block,
+            trustedBlock: po.trustedBlock,
+        }));
+    }
+    if head.header != po.trustedHeaderHash {
+        return Err(Error::TrustedHeaderMismatch(TrustedHeaderMismatch {
+            expected: head.header,
+            found: po.trustedHeaderHash,
+        }));
+    }
+    if po.targetBlock <= head.block {
+        return Err(Error::TargetBlockNotInRange(TargetBlockNotInRange {
+            latestBlock: head.block,
+            targetBlock: po.targetBlock,
+        }));
+    }
+    if po.targetBlock - head.block > DATA_COMMITMENT_MAX {
+        return Err(Error::TargetBlockTooFar(TargetBlockTooFar {
+            targetBlock: po.targetBlock,
+            maxBlock: head.block + DATA_COMMITMENT_MAX,
+        }));
+    }
+
+    // verify sp1 plonk proof.
+    if !precompiles::gnark_verify(
+        vkey_hash.to_vec(),
+        public_values.to_vec(),
+        proof.to_vec(),
+        vkey.to_vec(),
+    ) {
+        return Err(Error::InvalidProof(InvalidProof {}));
+    }
+    Ok(po)
+}
+
+/// Stores the target header and the data commitment of a checked header range starting at `start_block`.
+fn store_header_range(start_block: u64, po: &ProofOutputs) {
+    let proof_nonce = Blobstream::state_proof_nonce().get();
+    Blobstream::header_hashes().insert(po.targetBlock, po.targetHeaderHash);
+    Blobstream::data_commitments().insert(proof_nonce, po.dataCommitment);
+    Blobstream::state_proof_nonce().set(proof_nonce + U256::from(1));
+    Blobstream::latest_block().set(po.targetBlock);
+
+    HeadUpdate {
+        blockNumber: po.targetBlock,
+        headerHash: po.targetHeaderHash,
+    }
+    .emit();
+    DataCommitmentStored {
+        proofNonce: proof_nonce,
+        startBlock: start_block,
+        endBlock: po.targetBlock,
+        dataCommitment: po.dataCommitment,
+    }
+    .emit();
+}
+
+/// Returns the program vkey hash and program vkey.
+fn get_vkey_hash_and_vkey() -> (Vec<u8>, Vec<u8>) {
+    let vkey_hash = Blobstream::program_vkey_hash().get();
+    let vkey = Blobstream::program_vkey().get();
+    (vkey_hash, vkey)
 }
@@ -0,0 +1,143 @@
+use crate::binary_merkle_tree::{compute_range_root, range_side_nodes, subtree_root};
+use crate::error::{Error, InvalidNamespaceMerkleProof, InvalidShareRange, SharesCountMismatch};
+use crate::input_type::{Namespace, NamespaceMerkleMultiproof, NamespaceNode};
+use crate::{Bytes, FixedBytes, U256};
+use sha2::Digest;
+
+// Namespaced merkle tree of the shares of a row of a Celestia block, as the upstream
+// `NamespaceMerkleTree` library. A node commits to the min and max namespace of its leaves.
+
+/// Returns the namespace of the parity shares, it is not included in the max namespace of a node.
+pub fn parity_namespace() -> Namespace {
+    Namespace {
+        version: FixedBytes::new([0xff; 1]),
+        id: FixedBytes::new([0xff; 28]),
+    }
+}
+
+/// Returns the version followed by the id of the namespace.
+pub fn namespace_bytes(namespace: &Namespace) -> Vec<u8> {
+    [namespace.version.as_slice(), namespace.id.as_slice()].concat()
+}
+
+/// Returns the min and max namespace followed by the digest of the node,
+/// the leaf data of a row root in the data root tree.
+pub fn node_bytes(node: &NamespaceNode) -> Bytes {
+    [
+        namespace_bytes(&node.min),
+        namespace_bytes(&node.max),
+        node.digest.to_vec(),
+    ]
+    .concat()
+    .into()
+}
+
+/// Returns the leaf of the share data with the namespace.
+pub fn leaf_digest(namespace: &Namespace, data: &[u8]) -> NamespaceNode {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(namespace_bytes(namespace));
+    hasher.update(data);
+    NamespaceNode {
+        min: namespace.clone(),
+        max: namespace.clone(),
+        digest: FixedBytes::new(hasher.finalize().into()),
+    }
+}
+
+/// Returns the parent of the nodes.
+pub fn node_digest(left: &NamespaceNode, right: &NamespaceNode) -> NamespaceNode {
+    let parity = parity_namespace();
+    let max = if left.min == parity {
+        parity
+    } else if right.min == parity {
+        left.max.clone()
+    } else {
+        right.max.clone()
+    };
+    let mut hasher = sha2::Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(node_bytes(left));
+    hasher.update(node_bytes(right));
+    NamespaceNode {
+        min: left.min.clone(),
+        max,
+        digest: FixedBytes::new(hasher.finalize().into()),
+    }
+}
+
+/// Verifies the multiproof of the shares with the namespace, the leaves beginKey..endKey of the tree with `root`.
+/// Returns an error if the proof is malformed, and false if the proof is for a different root.
+pub fn verify_multi(
+    root: &NamespaceNode,
+    proof: &NamespaceMerkleMultiproof,
+    namespace: &Namespace,
+    data: &[Bytes],
+) -> Result<bool, Error> {
+    let leaves: Vec<NamespaceNode> = data
+        .iter()
+        .map(|share| leaf_digest(namespace, share))
+        .collect();
+    verify_multi_hashes(root, proof, &leaves)
+}
+
+/// Verifies the multiproof of the leaves beginKey..endKey of the tree with `root`.
+pub fn verify_multi_hashes(
+    root: &NamespaceNode,
+    proof: &NamespaceMerkleMultiproof,
+    leaves: &[NamespaceNode],
+) -> Result<bool, Error> {
+    let (begin, end) = proof_range(proof)?;
+    if leaves.len() as u64 != end - begin {
+        return Err(Error::SharesCountMismatch(SharesCountMismatch {
+            expected: U256::from(end - begin),
+            found: U256::from(leaves.len()),
+        }));
+    }
+
+    let node = compute_range_root(begin, end, leaves, &proof.sideNodes, node_digest).ok_or(
+        Error::InvalidNamespaceMerkleProof(InvalidNamespaceMerkleProof {}),
+    )?;
+    Ok(node == *root)
+}
+
+/// Returns the leaf range [beginKey, endKey) of the proof, an error if it is empty or too large.
+pub fn proof_range(proof: &NamespaceMerkleMultiproof) -> Result<(u64, u64), Error> {
+    let invalid = || {
+        Error::InvalidShareRange(InvalidShareRange {
+            beginKey: proof.beginKey,
+            endKey: proof.endKey,
+        })
+    };
+    let begin = u32::try_from(proof.beginKey).map_err(|_| invalid())?;
+    let end = u32::try_from(proof.endKey).map_err(|_| invalid())?;
+    if begin >= end {
+        return Err(invalid());
+    }
+    Ok((begin as u64, end as u64))
+}
+
+/// Returns the root of the tree with the leaves, None if there are none.
+pub fn root(leaves: &[NamespaceNode]) -> Option<NamespaceNode> {
+    (!leaves.is_empty()).then(|| subtree_root(leaves, &node_digest))
+}
+
+/// Returns the multiproof of the leaves begin..end of the tree with the leaves.
+pub fn prove(
+    leaves: &[NamespaceNode],
+    begin: u32,
+    end: u32,
+) -> Result<NamespaceMerkleMultiproof, Error> {
+    if begin >= end || end as usize > leaves.len() {
+        return Err(Error::InvalidShareRange(InvalidShareRange {
+            beginKey: U256::from(begin),
+            endKey: U256::from(end),
+        }));
+    }
+    let range = begin as usize..end as usize;
+    Ok(NamespaceMerkleMultiproof {
+        beginKey: U256::from(begin),
+        endKey: U256::from(end),
+        sideNodes: range_side_nodes(leaves, range, &node_digest),
+    })
+}
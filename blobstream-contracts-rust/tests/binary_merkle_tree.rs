@@ -1,12 +1,25 @@
-use blobstream_contracts_rust::{binary_merkle_tree::verify, input_type::BinaryMerkleProof};
-use seq_wasm_sdk::{bytes, fixed_bytes, FixedBytes, U256};
+use blobstream_contracts_rust::binary_merkle_tree::{
+    compute_root_hash, leaf_digest, path_length_from_key, verify, verify_multi, verify_range,
+    MerkleTree, MAX_NUM_LEAVES,
+};
+use blobstream_contracts_rust::error::{
+    Error, InvalidLeafKeys, InvalidLeafRange, InvalidMerkleProof, KeyNotInTree, LeavesCountMismatch,
+};
+use blobstream_contracts_rust::input_type::{
+    BinaryMerkleMultiproof, BinaryMerkleProof, BinaryMerkleRangeProof, DataRootTuple,
+};
+use blobstream_contracts_rust::nmt::node_bytes;
+use seq_wasm_sdk::{bytes, fixed_bytes, Bytes, FixedBytes, SolValue, U256};
+
+mod fixtures;
+
 fn set_up() -> BinaryMerkleProof {
     let side_nodes = vec![FixedBytes::<32>::new([0; 32])];
     let key = U256::from(0);
     let num_leaves = U256::from(1);
     BinaryMerkleProof {
         sideNodes: side_nodes,
-        key: key,
+        key,
         numLeaves: num_leaves,
     }
 }
@@ -20,11 +33,11 @@ fn test_verify_none() {
     let num_leaves = U256::from(0);
     let b_m_p = BinaryMerkleProof {
         sideNodes: side_nodes,
-        key: key,
+        key,
         numLeaves: num_leaves,
     };
     let data = bytes!();
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -32,14 +45,14 @@ fn test_verify_one_leaf_empty() {
     let root = fixed_bytes!("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d");
     let b_m_p = set_up();
     let data = bytes!();
-    assert_eq!(true, verify(root, b_m_p, data));
+    assert!(verify(root, b_m_p, data));
 }
 #[test]
 fn test_verify_one_leaf_some() {
     let root = fixed_bytes!("48c90c8ae24688d6bef5d48a30c2cc8b6754335a8db21793cc0a8e3bed321729");
     let b_m_p = set_up();
     let data = bytes!("deadbeef");
-    assert_eq!(true, verify(root, b_m_p, data));
+    assert!(verify(root, b_m_p, data));
 }
 
 #[test]
@@ -47,7 +60,7 @@ fn test_verify_one_leaf_01() {
     let root = fixed_bytes!("b413f47d13ee2fe6c845b2ee141af81de858df4ec549a58b7970bb96645bc8d2");
     let b_m_p = set_up();
     let data = bytes!("01");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -66,7 +79,7 @@ fn test_verify_leaf_one_of_eight() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -85,7 +98,7 @@ fn test_verify_leaf_two_of_eight() {
         numLeaves: num_leaves,
     };
     let data = bytes!("02");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -104,7 +117,7 @@ fn test_verify_leaf_three_of_eight() {
         numLeaves: num_leaves,
     };
     let data = bytes!("03");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -123,7 +136,7 @@ fn test_verify_seven_of_eight() {
         numLeaves: num_leaves,
     };
     let data = bytes!("07");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -142,7 +155,7 @@ fn test_verify_eight_of_eight() {
         numLeaves: num_leaves,
     };
     let data = bytes!("08");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 // Test vectors:
@@ -167,7 +180,7 @@ fn test_verify_proof_of_five_leaves() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(true, verify(root, b_m_p, data))
+    assert!(verify(root, b_m_p, data))
 }
 
 #[test]
@@ -187,7 +200,7 @@ fn test_verify_invalid_proof_root() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -207,7 +220,7 @@ fn test_verify_invalid_proof_key() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -227,7 +240,7 @@ fn test_verify_invalid_proof_number_of_leaves() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -246,7 +259,7 @@ fn test_verify_invalid_proof_side_nodes() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -265,7 +278,7 @@ fn test_verify_invalid_proof_data() {
         numLeaves: num_leaves,
     };
     let data = bytes!("012345"); // correct data: 01
-    assert_eq!(false, verify(root, b_m_p, data))
+    assert!(!verify(root, b_m_p, data))
 }
 
 #[test]
@@ -280,5 +293,510 @@ fn test_same_key_and_leaves_number() {
         numLeaves: num_leaves,
     };
     let data = bytes!("01");
-    assert_eq!(false, verify(root, b_m_p, data));
+    assert!(!verify(root, b_m_p, data));
+}
+
+// Range proofs of the eight leaves 0x01..0x08 and five leaves 0x00..0x04 of the single leaf
+// vectors above, the side nodes are subtree roots taken from their proofs.
+const ROOT_OF_EIGHT: FixedBytes<32> =
+    fixed_bytes!("c1ad6548cb4c7663110df219ec8b36ca63b01158956f4be31a38a88d0c7f7071");
+const ROOT_OF_FIVE: FixedBytes<32> =
+    fixed_bytes!("b855b42d6c30f5b087e05266783fbd6e394f7b926013ccaa67700a8b0c5a596f");
+
+fn range_proof(begin: u64, end: u64, side_nodes: Vec<FixedBytes<32>>) -> BinaryMerkleRangeProof {
+    BinaryMerkleRangeProof {
+        sideNodes: side_nodes,
+        beginKey: U256::from(begin),
+        endKey: U256::from(end),
+    }
+}
+
+fn multiproof(keys: &[u64], side_nodes: Vec<FixedBytes<32>>) -> BinaryMerkleMultiproof {
+    BinaryMerkleMultiproof {
+        sideNodes: side_nodes,
+        keys: keys.iter().map(|key| U256::from(*key)).collect(),
+    }
+}
+
+/// Leaves begin..end of the tree with the leaves `first`, `first + 1`, ...
+fn leaves(first: u8, begin: u8, end: u8) -> Vec<Bytes> {
+    (begin..end).map(|i| Bytes::from(vec![first + i])).collect()
+}
+
+#[test]
+fn test_verify_range_of_eight() {
+    let leaf_1 = fixed_bytes!("b413f47d13ee2fe6c845b2ee141af81de858df4ec549a58b7970bb96645bc8d2");
+    let leaf_2 = fixed_bytes!("fcf0a6c700dd13e274b6fba8deea8dd9b26e4eedde3495717cac8408c9c5177f");
+    let leaf_4 = fixed_bytes!("4f35212d12f9ad2036492c95f1fe79baf4ec7bd9bef3dffa7579f2293ff546a4");
+    let leaf_8 = fixed_bytes!("b4c43b50bf245bd727623e3c775a8fcfb8d823d00b57dd65f7f79dd33f126315");
+    let leaves_1_2 =
+        fixed_bytes!("6bcf0e2e93e0a18e22789aee965e6553f4fbe93f0acfc4a705d691c8311c4965");
+    let leaves_3_4 =
+        fixed_bytes!("78850a5ab36238b076dd99fd258c70d523168704247988a94caa8c9ccd056b8d");
+    let leaves_5_6 =
+        fixed_bytes!("90eeb2c4a04ec33ee4dd2677593331910e4203db4fcc120a6cdb95b13cfe83f0");
+    let leaves_1_4 =
+        fixed_bytes!("fa02d31a63cc11cc624881e52af14af7a1c6ab745efa71021cb24086b9b1793f");
+    let leaves_5_8 =
+        fixed_bytes!("4301a067262bbb18b4919742326f6f6d706099f9c0e8b0f2db7b88f204b2cf09");
+
+    let cases = [
+        (0, 8, vec![]),
+        (0, 1, vec![leaf_2, leaves_3_4, leaves_5_8]),
+        (0, 2, vec![leaves_3_4, leaves_5_8]),
+        (0, 4, vec![leaves_5_8]),
+        (1, 3, vec![leaf_1, leaf_4, leaves_5_8]),
+        (2, 4, vec![leaves_1_2, leaves_5_8]),
+        (2, 7, vec![leaves_1_2, leaf_8]),
+        (4, 8, vec![leaves_1_4]),
+        (6, 8, vec![leaves_1_4, leaves_5_6]),
+    ];
+    for (begin, end, side_nodes) in cases {
+        let proof = range_proof(begin, end, side_nodes);
+        let data = leaves(1, begin as u8, end as u8);
+        assert_eq!(
+            verify_range(ROOT_OF_EIGHT, &proof, &data),
+            Ok(true),
+            "range {begin}..{end}"
+        );
+        // the leaves shifted by one.
+        let data = leaves(2, begin as u8, end as u8);
+        assert_eq!(verify_range(ROOT_OF_EIGHT, &proof, &data), Ok(false));
+    }
+}
+
+#[test]
+fn test_verify_range_of_five() {
+    let leaf_0 = fixed_bytes!("96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7");
+    let leaf_4 = fixed_bytes!("4f35212d12f9ad2036492c95f1fe79baf4ec7bd9bef3dffa7579f2293ff546a4");
+    let leaves_2_3 =
+        fixed_bytes!("52c56b473e5246933e7852989cd9feba3b38f078742b93afff1e65ed46797825");
+
+    let cases = [
+        (0, 5, vec![]),
+        (0, 4, vec![leaf_4]),
+        (1, 2, vec![leaf_0, leaves_2_3, leaf_4]),
+        (1, 4, vec![leaf_0, leaf_4]),
+        (1, 5, vec![leaf_0]),
+    ];
+    for (begin, end, side_nodes) in cases {
+        let proof = range_proof(begin, end, side_nodes);
+        let data = leaves(0, begin as u8, end as u8);
+        assert_eq!(
+            verify_range(ROOT_OF_FIVE, &proof, &data),
+            Ok(true),
+            "range {begin}..{end}"
+        );
+    }
+}
+
+#[test]
+fn test_verify_range_invalid_proof() {
+    let leaves_5_8 =
+        fixed_bytes!("4301a067262bbb18b4919742326f6f6d706099f9c0e8b0f2db7b88f204b2cf09");
+    let proof = range_proof(0, 4, vec![leaves_5_8]);
+
+    assert_eq!(
+        verify_range(ROOT_OF_EIGHT, &proof, &leaves(1, 0, 3)),
+        Err(Error::LeavesCountMismatch(LeavesCountMismatch {
+            expected: U256::from(4),
+            found: U256::from(3),
+        }))
+    );
+    assert_eq!(
+        verify_range(ROOT_OF_EIGHT, &range_proof(4, 4, vec![]), &[]),
+        Err(Error::InvalidLeafRange(InvalidLeafRange {
+            beginKey: U256::from(4),
+            endKey: U256::from(4),
+        }))
+    );
+    // missing and extra side nodes.
+    let data = leaves(1, 0, 4);
+    assert_eq!(
+        verify_range(ROOT_OF_EIGHT, &range_proof(0, 4, vec![]), &data),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_range(
+            ROOT_OF_EIGHT,
+            &range_proof(0, 4, vec![leaves_5_8, leaves_5_8]),
+            &data
+        ),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_verify_multi_of_eight() {
+    let leaf_1 = fixed_bytes!("b413f47d13ee2fe6c845b2ee141af81de858df4ec549a58b7970bb96645bc8d2");
+    let leaves_3_4 =
+        fixed_bytes!("78850a5ab36238b076dd99fd258c70d523168704247988a94caa8c9ccd056b8d");
+    let leaves_5_6 =
+        fixed_bytes!("90eeb2c4a04ec33ee4dd2677593331910e4203db4fcc120a6cdb95b13cfe83f0");
+    let leaves_5_8 =
+        fixed_bytes!("4301a067262bbb18b4919742326f6f6d706099f9c0e8b0f2db7b88f204b2cf09");
+    let leaf_3 = leaf_digest(bytes!("03"));
+
+    let cases = [
+        (vec![0, 1, 6, 7], vec![leaves_3_4, leaves_5_6]),
+        (vec![1, 3], vec![leaf_1, leaf_3, leaves_5_8]),
+        (vec![0, 1, 2, 3], vec![leaves_5_8]),
+    ];
+    for (keys, side_nodes) in cases {
+        let proof = multiproof(&keys, side_nodes);
+        let data: Vec<Bytes> = keys
+            .iter()
+            .map(|key| Bytes::from(vec![*key as u8 + 1]))
+            .collect();
+        assert_eq!(
+            verify_multi(ROOT_OF_EIGHT, &proof, &data),
+            Ok(true),
+            "keys {keys:?}"
+        );
+    }
+}
+
+#[test]
+fn test_verify_multi_invalid_proof() {
+    let leaves_3_4 =
+        fixed_bytes!("78850a5ab36238b076dd99fd258c70d523168704247988a94caa8c9ccd056b8d");
+    let leaves_5_6 =
+        fixed_bytes!("90eeb2c4a04ec33ee4dd2677593331910e4203db4fcc120a6cdb95b13cfe83f0");
+    let side_nodes = vec![leaves_3_4, leaves_5_6];
+    let data = [bytes!("01"), bytes!("02"), bytes!("07"), bytes!("08")];
+    let invalid_keys = Err(Error::InvalidLeafKeys(InvalidLeafKeys {}));
+
+    for keys in [&[][..], &[0, 1, 7, 6], &[0, 1, 1, 7], &[0, 1, 6, 1 << 32]] {
+        let proof = multiproof(keys, side_nodes.clone());
+        assert_eq!(
+            verify_multi(ROOT_OF_EIGHT, &proof, &data[..keys.len()]),
+            invalid_keys,
+            "keys {keys:?}"
+        );
+    }
+    assert_eq!(
+        verify_multi(
+            ROOT_OF_EIGHT,
+            &multiproof(&[0, 1, 6, 7], side_nodes.clone()),
+            &data[..3]
+        ),
+        Err(Error::LeavesCountMismatch(LeavesCountMismatch {
+            expected: U256::from(4),
+            found: U256::from(3),
+        }))
+    );
+    // missing side nodes, and the side nodes of other keys.
+    assert_eq!(
+        verify_multi(
+            ROOT_OF_EIGHT,
+            &multiproof(&[0, 1, 6, 7], side_nodes[..1].to_vec()),
+            &data
+        ),
+        Err(Error::InvalidMerkleProof(InvalidMerkleProof {}))
+    );
+    assert_eq!(
+        verify_multi(
+            ROOT_OF_EIGHT,
+            &multiproof(&[0, 1, 4, 5], side_nodes.clone()),
+            &data
+        ),
+        Ok(false)
+    );
+    // a leaf swapped with another.
+    let swapped = [bytes!("01"), bytes!("02"), bytes!("08"), bytes!("07")];
+    assert_eq!(
+        verify_multi(
+            ROOT_OF_EIGHT,
+            &multiproof(&[0, 1, 6, 7], side_nodes),
+            &swapped
+        ),
+        Ok(false)
+    );
+}
+
+/// Row roots and data hashes of the celestia blocks 2 and 6 of the celestia-types crate test data,
+/// headers captured from a celestia-node. The data hash is the RFC 6962 root of the row roots
+/// followed by the column roots, computed by `merkle.HashFromByteSlices`. The squares are 2x2 with
+/// column roots equal to the row roots.
+fn celestia_data_roots() -> Vec<(FixedBytes<32>, Vec<Bytes>)> {
+    let blocks = [
+        (
+            fixed_bytes!("8cb9845830812059a5872387d695c142cc82fc708c909163cd836733dab7ec4d"),
+            [
+                bytes!("00000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000001c4134948019292646525a07d9a3575356241c2664b5bb7f4c9885f6dc21237fc"),
+                bytes!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff76c56ca23826d81d4b90399579b1acf236469357abf85d3f2ffeb9549d272bf3"),
+            ],
+        ),
+        (
+            fixed_bytes!("3777abb70321a5486bd4a1614507726042fd282529d008c2fb1a02fd2f904fa2"),
+            [
+                bytes!("00000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000001594bb5682059f2ecf52edb391d1ad3b3409c007188a715bf58fd09dcc14a6c58"),
+                bytes!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff27872fab788605ecf7b0052802c44eaf1337355ef701eed681e8ef11b1acb648"),
+            ],
+        ),
+    ];
+    blocks
+        .into_iter()
+        .map(|(data_hash, row_roots)| (data_hash, [row_roots.clone(), row_roots].concat()))
+        .collect()
+}
+
+#[test]
+fn test_celestia_data_roots() {
+    for (data_hash, leaves) in celestia_data_roots() {
+        let tree = MerkleTree::new(&leaves);
+        assert_eq!(tree.root(), data_hash);
+        for key in 0..leaves.len() as u32 {
+            let proof = tree.prove(key).unwrap();
+            assert!(verify(data_hash, proof, leaves[key as usize].clone()));
+        }
+        for begin in 0..leaves.len() as u32 {
+            for end in begin + 1..=leaves.len() as u32 {
+                let proof = tree.prove_range(begin, end).unwrap();
+                let range = &leaves[begin as usize..end as usize];
+                assert_eq!(
+                    verify_range(data_hash, &proof, range),
+                    Ok(true),
+                    "range {begin}..{end}"
+                );
+            }
+        }
+    }
+    // the row roots of a block against the data hash of the other.
+    let blocks = celestia_data_roots();
+    let proof = MerkleTree::new(&blocks[0].1).prove_range(0, 2).unwrap();
+    assert_eq!(
+        verify_range(blocks[1].0, &proof, &blocks[0].1[..2]),
+        Ok(false)
+    );
+}
+
+#[test]
+fn test_celestia_multiproofs() {
+    // every set of keys of the row and column roots, against the data hash of the header.
+    for (data_hash, leaves) in celestia_data_roots() {
+        let tree = MerkleTree::new(&leaves);
+        for mask in 1..1u32 << leaves.len() {
+            let keys: Vec<u32> = (0..leaves.len() as u32)
+                .filter(|key| mask & (1 << key) != 0)
+                .collect();
+            let proof = tree.prove_multi(&keys).unwrap();
+            let data: Vec<Bytes> = keys
+                .iter()
+                .map(|key| leaves[*key as usize].clone())
+                .collect();
+            assert_eq!(
+                verify_multi(data_hash, &proof, &data),
+                Ok(true),
+                "keys {keys:?}"
+            );
+        }
+    }
+
+    // the first two rows and four columns of the data availability header of the fixture, its
+    // root computed from the single leaf proofs.
+    let square = fixtures::extended_data_square();
+    let leaves: Vec<Bytes> = square
+        .row_roots
+        .iter()
+        .chain(&square.column_roots)
+        .map(node_bytes)
+        .collect();
+    let tree = MerkleTree::new(&leaves);
+    let proof = tree.prove(0).unwrap();
+    let root = compute_root_hash(0, 16, leaf_digest(leaves[0].clone()), &proof.sideNodes).unwrap();
+    let keys = [0, 1, 8, 9, 10, 11];
+    let proof = tree.prove_multi(&keys).unwrap();
+    // the subtrees of rows 2..4, rows 4..8 and columns 4..8.
+    assert_eq!(proof.sideNodes.len(), 3);
+    let data: Vec<Bytes> = keys
+        .iter()
+        .map(|key| leaves[*key as usize].clone())
+        .collect();
+    assert_eq!(verify_multi(root, &proof, &data), Ok(true));
+    assert!(verify_multi(root, &proof, &data[..5]).is_err());
+}
+
+#[test]
+fn test_merkle_tree_root() {
+    assert_eq!(MerkleTree::new(&leaves(1, 0, 8)).root(), ROOT_OF_EIGHT);
+    assert_eq!(MerkleTree::new(&leaves(0, 0, 5)).root(), ROOT_OF_FIVE);
+    assert_eq!(
+        MerkleTree::new(&[]).root(),
+        fixed_bytes!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+    );
+    assert_eq!(
+        MerkleTree::new(&[bytes!("01")]).root(),
+        fixed_bytes!("b413f47d13ee2fe6c845b2ee141af81de858df4ec549a58b7970bb96645bc8d2")
+    );
+}
+
+#[test]
+fn test_merkle_tree_prove_vectors() {
+    let tree = MerkleTree::new(&leaves(1, 0, 8));
+    let proof = tree.prove(7).unwrap();
+    assert_eq!(
+        proof.sideNodes,
+        vec![
+            fixed_bytes!("2ecd8a6b7d2845546659ad4cf443533cf921b19dc81fa83934e83821b4dfdcb7"),
+            fixed_bytes!("90eeb2c4a04ec33ee4dd2677593331910e4203db4fcc120a6cdb95b13cfe83f0"),
+            fixed_bytes!("fa02d31a63cc11cc624881e52af14af7a1c6ab745efa71021cb24086b9b1793f"),
+        ]
+    );
+    assert_eq!((proof.key, proof.numLeaves), (U256::from(7), U256::from(8)));
+
+    let proof = MerkleTree::new(&leaves(0, 0, 5)).prove(1).unwrap();
+    assert_eq!(
+        proof.sideNodes,
+        vec![
+            fixed_bytes!("96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7"),
+            fixed_bytes!("52c56b473e5246933e7852989cd9feba3b38f078742b93afff1e65ed46797825"),
+            fixed_bytes!("4f35212d12f9ad2036492c95f1fe79baf4ec7bd9bef3dffa7579f2293ff546a4"),
+        ]
+    );
+}
+
+#[test]
+fn test_merkle_tree_round_trip() {
+    for size in 1..=17u64 {
+        let data: Vec<Bytes> = (0..size)
+            .map(|i| {
+                DataRootTuple {
+                    height: U256::from(i),
+                    dataRoot: FixedBytes::from([i as u8; 32]),
+                }
+                .abi_encode()
+                .into()
+            })
+            .collect();
+        let tree = MerkleTree::new(&data);
+        let root = tree.root();
+        for key in 0..size as u32 {
+            let proof = tree.prove(key).unwrap();
+            assert!(
+                verify(root, proof, data[key as usize].clone()),
+                "size {size} key {key}"
+            );
+        }
+        for begin in 0..size as u32 {
+            for end in begin + 1..=size as u32 {
+                let proof = tree.prove_range(begin, end).unwrap();
+                let range = &data[begin as usize..end as usize];
+                assert_eq!(
+                    verify_range(root, &proof, range),
+                    Ok(true),
+                    "size {size} range {begin}..{end}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_merkle_tree_multiproof_round_trip() {
+    for size in 1..=9u32 {
+        let data = leaves(0, 0, size as u8);
+        let tree = MerkleTree::new(&data);
+        let root = tree.root();
+        for mask in 1..1u32 << size {
+            let keys: Vec<u32> = (0..size).filter(|key| mask & (1 << key) != 0).collect();
+            let proof = tree.prove_multi(&keys).unwrap();
+            let proven: Vec<Bytes> = keys.iter().map(|key| data[*key as usize].clone()).collect();
+            assert_eq!(
+                verify_multi(root, &proof, &proven),
+                Ok(true),
+                "size {size} keys {keys:?}"
+            );
+            // consecutive keys have the side nodes of their range proof.
+            let (begin, end) = (keys[0], keys[keys.len() - 1] + 1);
+            if end - begin == keys.len() as u32 {
+                let range_proof = tree.prove_range(begin, end).unwrap();
+                assert_eq!(proof.sideNodes, range_proof.sideNodes);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_merkle_tree_prove_out_of_tree() {
+    let tree = MerkleTree::new(&leaves(1, 0, 8));
+    assert_eq!(
+        tree.prove(8).err(),
+        Some(Error::KeyNotInTree(KeyNotInTree {
+            key: U256::from(8),
+            numLeaves: U256::from(8)
+        }))
+    );
+    assert_eq!(
+        tree.prove_range(4, 9).err(),
+        Some(Error::InvalidLeafRange(InvalidLeafRange {
+            beginKey: U256::from(4),
+            endKey: U256::from(9),
+        }))
+    );
+    assert_eq!(
+        tree.prove_multi(&[2, 8]).err(),
+        Some(Error::KeyNotInTree(KeyNotInTree {
+            key: U256::from(8),
+            numLeaves: U256::from(8)
+        }))
+    );
+    assert_eq!(
+        tree.prove_multi(&[3, 2]).err(),
+        Some(Error::InvalidLeafKeys(InvalidLeafKeys {}))
+    );
+}
+
+#[test]
+fn test_verify_num_leaves_too_large() {
+    let root = fixed_bytes!("b855b42d6c30f5b087e05266783fbd6e394f7b926013ccaa67700a8b0c5a596f");
+    let b_m_p = BinaryMerkleProof {
+        sideNodes: vec![FixedBytes::<32>::new([1; 32]); 33],
+        key: U256::from(1),
+        numLeaves: U256::from(MAX_NUM_LEAVES + 1),
+    };
+    assert!(!verify(root, b_m_p, bytes!("01")));
+}
+
+#[test]
+fn test_verify_key_too_large() {
+    // the path of a key after the last leaf follows the right most leaf.
+    let b_m_p = BinaryMerkleProof {
+        sideNodes: vec![FixedBytes::<32>::new([1; 32]); 3],
+        key: U256::MAX,
+        numLeaves: U256::from(5),
+    };
+    assert!(!verify(ROOT_OF_FIVE, b_m_p, bytes!("01")));
+}
+
+#[test]
+fn test_path_length_from_key() {
+    assert_eq!(path_length_from_key(0, 1), 0);
+    assert_eq!(path_length_from_key(0, 8), 3);
+    assert_eq!(path_length_from_key(7, 8), 3);
+    assert_eq!(path_length_from_key(3, 5), 3);
+    assert_eq!(path_length_from_key(4, 5), 1);
+    assert_eq!(path_length_from_key(5, 6), 2);
+    assert_eq!(path_length_from_key(0, MAX_NUM_LEAVES), 32);
+}
+
+#[test]
+fn test_compute_root_hash_invalid_side_nodes() {
+    let tree = MerkleTree::new(&leaves(1, 0, 8));
+    let proof = tree.prove(3).unwrap();
+    let leaf = leaf_digest(bytes!("04"));
+    assert_eq!(
+        compute_root_hash(3, 8, leaf, &proof.sideNodes),
+        Ok(ROOT_OF_EIGHT)
+    );
+    assert_eq!(
+        compute_root_hash(3, 8, leaf, &proof.sideNodes[1..]),
+        Err(Error::InvalidMerkleProof(InvalidMerkleProof {}))
+    );
+    let mut extra = vec![FixedBytes::<32>::new([1; 32])];
+    extra.extend(proof.sideNodes);
+    assert_eq!(
+        compute_root_hash(3, 8, leaf, &extra),
+        Err(Error::InvalidMerkleProof(InvalidMerkleProof {}))
+    );
 }
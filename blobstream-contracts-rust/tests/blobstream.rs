@@ -0,0 +1,1058 @@
+use blobstream_contracts_rust::binary_merkle_tree::{self, leaf_digest, MerkleTree};
+use blobstream_contracts_rust::error::{
+    AlreadyInitialized, ContractFrozen, EmptyBatch, Error, InvalidAccount, InvalidCalldata,
+    InvalidProof, InvalidProofNonce, LayoutVersionMismatch, MissingRole, RelayerNotApproved,
+    RowProofsCountMismatch, SharesCountMismatch, TargetBlockNotInRange, TargetBlockTooFar,
+    TrustedBlockMismatch, TrustedHeaderMismatch,
+};
+use blobstream_contracts_rust::events::{
+    DataCommitmentStored, FreezeUpdated, HeadUpdate, RelayerApprovalUpdated,
+};
+use blobstream_contracts_rust::input_type::{
+    AttestationProof, BinaryMerkleMultiproof, BinaryMerkleProof, BinaryMerkleRangeProof,
+    CommitHeaderRangeInput, DataRootTuple, Namespace, ProofOutputs, SharesProof, VAInput,
+};
+use blobstream_contracts_rust::nmt;
+use blobstream_contracts_rust::{
+    accept_guardian, block_height_to_header_hash, commit_header_range, commit_header_ranges,
+    data_commitment, frozen, grant_role, guardian as guardian_view, initializer, latest_block,
+    migrate_roles, program_vkey_hash, role_members, set_check_relayer, set_relayer_approval,
+    state_proof_nonce, transfer_guardian, update_freeze, verify_attestation,
+    verify_attestation_multi, verify_attestation_range, verify_attestations,
+    verify_shares_to_data_root_tuple,
+};
+use seq_wasm_sdk::access_control::{self, GUARDIAN};
+use seq_wasm_sdk::migration::{self, LayoutMigrated};
+use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+use seq_wasm_sdk::types::Address;
+use seq_wasm_sdk::utils::TxContext;
+use seq_wasm_sdk::{
+    fixed_bytes, mock, Bytes, FixedBytes, SolEvent, SolInterface, SolType, SolValue, U256,
+};
+
+mod fixtures;
+
+type Entrypoint = extern "C" fn(*const TxContext, *const u8, u32) -> bool;
+
+const GENESIS_HEIGHT: u64 = 2202300;
+const GENESIS_HEADER: FixedBytes<32> =
+    fixed_bytes!("188b708bee180f43e3a252471754fd35283a6b09a6fd02f5b9130cc15604f80b");
+
+fn guardian() -> Address {
+    Address::new([1; Address::LEN])
+}
+
+fn call(entrypoint: Entrypoint, msg_sender: Address, calldata: &[u8]) -> bool {
+    let tx_ctx = TxContext::builder().msg_sender(msg_sender).build();
+    entrypoint(&tx_ctx, calldata.as_ptr(), calldata.len() as u32)
+}
+
+/// Decodes the error the last failed call reverted with.
+fn revert_error() -> Error {
+    Error::abi_decode(&mock::take_revert_data(), true).unwrap()
+}
+
+fn set_up() {
+    mock::reset();
+    let calldata = (
+        GENESIS_HEIGHT,
+        GENESIS_HEADER,
+        Bytes::from(vec![1; 32]),
+        Bytes::from(vec![2; 64]),
+    )
+        .abi_encode_params();
+    assert!(call(initializer, guardian(), &calldata));
+    // drop the role events of the initializer.
+    mock::take_events();
+}
+
+/// Writes the state of a contract initialized before layout versioning, with the guardian in slot 2.
+fn set_up_layout_version_0() {
+    mock::reset();
+    StorageValue::<bool>::new(0).set(true);
+    StorageValue::<bool>::new(1).set(false);
+    StorageValue::<Address>::new(2).set(guardian());
+    StorageValue::<u64>::new(3).set(GENESIS_HEIGHT);
+    StorageValue::<U256>::new(4).set(U256::from(1));
+    StorageValue::<Vec<u8>>::new(5).set(vec![1; 32]);
+    StorageValue::<Vec<u8>>::new(6).set(vec![2; 64]);
+    StorageMap::<u64, FixedBytes<32>>::new(1).insert(GENESIS_HEIGHT, GENESIS_HEADER);
+}
+
+fn public_values(
+    trusted_header: FixedBytes<32>,
+    trusted_block: u64,
+    target_block: u64,
+    data_commitment: FixedBytes<32>,
+) -> Bytes {
+    ProofOutputs {
+        trustedHeaderHash: trusted_header,
+        targetHeaderHash: FixedBytes::from([target_block as u8; 32]),
+        dataCommitment: data_commitment,
+        trustedBlock: trusted_block,
+        targetBlock: target_block,
+        validatorBitmap: U256::ZERO,
+    }
+    .abi_encode()
+    .into()
+}
+
+fn commit_input(
+    trusted_header: FixedBytes<32>,
+    trusted_block: u64,
+    target_block: u64,
+    data_commitment: FixedBytes<32>,
+) -> Vec<u8> {
+    let public_values = public_values(trusted_header, trusted_block, target_block, data_commitment);
+    (Bytes::from(vec![3; 32]), public_values).abi_encode_params()
+}
+
+/// Calldata of `commit_header_ranges` for consecutive ranges from the genesis block to each target block.
+fn commit_batch_input(target_blocks: &[u64]) -> Vec<u8> {
+    let mut trusted_header = GENESIS_HEADER;
+    let mut trusted_block = GENESIS_HEIGHT;
+    let mut inputs = Vec::new();
+    for &target_block in target_blocks {
+        inputs.push(CommitHeaderRangeInput {
+            proof: Bytes::from(vec![3; 32]),
+            publicValues: public_values(
+                trusted_header,
+                trusted_block,
+                target_block,
+                FixedBytes::from([target_block as u8; 32]),
+            ),
+        });
+        trusted_header = FixedBytes::from([target_block as u8; 32]);
+        trusted_block = target_block;
+    }
+    (inputs,).abi_encode_params()
+}
+
+/// Calls a view and decodes the value it returned.
+fn view<T: SolValue + From<<T::SolType as SolType>::RustType>>(
+    entrypoint: Entrypoint,
+    calldata: &[u8],
+) -> T {
+    assert!(call(entrypoint, Address::default(), calldata));
+    T::abi_decode(&mock::take_return_data(), true).unwrap()
+}
+
+#[test]
+fn test_initializer_only_once() {
+    set_up();
+    let calldata = (1u64, FixedBytes::<32>::ZERO, Bytes::new(), Bytes::new()).abi_encode_params();
+    assert!(!call(initializer, guardian(), &calldata));
+    assert_eq!(
+        revert_error(),
+        Error::AlreadyInitialized(AlreadyInitialized {})
+    );
+}
+
+#[test]
+fn test_initializer_grants_roles() {
+    set_up();
+    assert!(access_control::has_role(access_control::ADMIN, &guardian()));
+    assert!(access_control::has_role(GUARDIAN, &guardian()));
+}
+
+#[test]
+fn test_transfer_guardian() {
+    set_up();
+    let new_guardian = Address::new([2; Address::LEN]);
+    let calldata = (Bytes::from(new_guardian.as_bytes().to_vec()),).abi_encode_params();
+    assert!(!call(transfer_guardian, new_guardian.clone(), &calldata));
+    assert!(call(transfer_guardian, guardian(), &calldata));
+
+    // the guardian keeps the role until the transfer is accepted.
+    let freeze = (true,).abi_encode_params();
+    assert!(!call(update_freeze, new_guardian.clone(), &freeze));
+    assert!(call(accept_guardian, new_guardian.clone(), &[]));
+    assert!(call(update_freeze, new_guardian, &freeze));
+    assert!(!call(update_freeze, guardian(), &freeze));
+
+    let calldata = (Bytes::from(vec![1, 2, 3]),).abi_encode_params();
+    assert!(!call(transfer_guardian, guardian(), &calldata));
+    assert_eq!(
+        revert_error(),
+        Error::InvalidAccount(InvalidAccount {
+            account: vec![1, 2, 3].into(),
+        })
+    );
+}
+
+#[test]
+fn test_grant_role_only_admin() {
+    set_up();
+    let account = Address::new([3; Address::LEN]);
+    let calldata = (GUARDIAN, Bytes::from(account.as_bytes().to_vec())).abi_encode_params();
+    assert!(!call(grant_role, account.clone(), &calldata));
+    assert!(call(grant_role, guardian(), &calldata));
+    assert!(access_control::has_role(GUARDIAN, &account));
+}
+
+#[test]
+fn test_initializer_sets_layout_version() {
+    set_up();
+    assert_eq!(migration::layout_version(), 1);
+}
+
+#[test]
+fn test_migrate_roles() {
+    set_up_layout_version_0();
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+
+    // the contract does not run on state of the old layout.
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::LayoutVersionMismatch(LayoutVersionMismatch {
+            expected: 1,
+            found: 0,
+        })
+    );
+
+    // only the guardian of the old layout can migrate.
+    assert!(!call(migrate_roles, Address::default(), &[]));
+    assert_eq!(
+        revert_error(),
+        Error::MissingRole(MissingRole {
+            role: GUARDIAN,
+            account: Address::default().as_bytes().to_vec().into(),
+        })
+    );
+    assert_eq!(migration::layout_version(), 0);
+
+    assert!(call(migrate_roles, guardian(), &[]));
+    assert_eq!(migration::layout_version(), 1);
+    assert!(access_control::has_role(access_control::ADMIN, &guardian()));
+    assert!(access_control::has_role(GUARDIAN, &guardian()));
+    assert_eq!(StorageValue::<Address>::new(2).try_get(), Ok(None));
+    let events = mock::take_events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        LayoutMigrated::decode_log_data(&events[2], true).unwrap(),
+        LayoutMigrated {
+            fromVersion: 0,
+            toVersion: 1,
+        }
+    );
+
+    // a migration runs once.
+    assert!(!call(migrate_roles, guardian(), &[]));
+    assert_eq!(
+        revert_error(),
+        Error::LayoutVersionMismatch(LayoutVersionMismatch {
+            expected: 0,
+            found: 1,
+        })
+    );
+
+    // the migrated state is used as is.
+    assert!(call(commit_header_range, Address::default(), &input));
+    assert!(call(
+        update_freeze,
+        guardian(),
+        &(true,).abi_encode_params()
+    ));
+}
+
+#[test]
+fn test_update_freeze_only_guardian() {
+    set_up();
+    let calldata = (true,).abi_encode_params();
+    assert!(!call(update_freeze, Address::default(), &calldata));
+    assert_eq!(
+        revert_error(),
+        Error::MissingRole(MissingRole {
+            role: GUARDIAN,
+            account: Address::default().as_bytes().to_vec().into(),
+        })
+    );
+    assert!(call(update_freeze, guardian(), &calldata));
+    let events = mock::take_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        FreezeUpdated::decode_log_data(&events[0], true).unwrap(),
+        FreezeUpdated { frozen: true }
+    );
+
+    // frozen contract rejects header range commitments.
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(revert_error(), Error::ContractFrozen(ContractFrozen {}));
+}
+
+#[test]
+fn test_commit_header_range() {
+    set_up();
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+
+    // invalid proof.
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(revert_error(), Error::InvalidProof(InvalidProof {}));
+
+    mock::set_verify_result(true);
+    assert!(call(commit_header_range, Address::default(), &input));
+    assert_eq!(mock::verify_calls(), 2);
+
+    // the new head and the data commitment are announced.
+    let events = mock::take_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        HeadUpdate::decode_log_data(&events[0], true).unwrap(),
+        HeadUpdate {
+            blockNumber: GENESIS_HEIGHT + 10,
+            headerHash: FixedBytes::from([(GENESIS_HEIGHT + 10) as u8; 32]),
+        }
+    );
+    let stored = DataCommitmentStored::decode_log_data(&events[1], true).unwrap();
+    assert_eq!(
+        stored,
+        DataCommitmentStored {
+            proofNonce: U256::from(1),
+            startBlock: GENESIS_HEIGHT,
+            endBlock: GENESIS_HEIGHT + 10,
+            dataCommitment: FixedBytes::ZERO,
+        }
+    );
+    // start block, end block and data commitment are indexed.
+    assert_eq!(events[1].topics().len(), 4);
+    assert_eq!(events[1].topics()[0], DataCommitmentStored::SIGNATURE_HASH);
+
+    // trusted block is no longer the latest block.
+    assert!(!call(commit_header_range, Address::default(), &input));
+}
+
+#[test]
+fn test_check_relayer() {
+    set_up();
+    mock::set_verify_result(true);
+    let relayer = Address::new([2; Address::LEN]);
+    let relayer_bytes = Bytes::from(relayer.as_bytes().to_vec());
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+
+    // only approved relayers can submit proofs once the check is enabled.
+    assert!(call(
+        set_check_relayer,
+        guardian(),
+        &(true,).abi_encode_params()
+    ));
+    assert!(!call(commit_header_range, relayer.clone(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::RelayerNotApproved(RelayerNotApproved {
+            relayer: relayer_bytes.clone(),
+        })
+    );
+
+    let calldata = (relayer_bytes.clone(), true).abi_encode_params();
+    assert!(!call(set_relayer_approval, relayer.clone(), &calldata));
+    mock::take_events();
+    assert!(call(set_relayer_approval, guardian(), &calldata));
+    assert_eq!(
+        RelayerApprovalUpdated::decode_log_data(&mock::take_events()[0], true).unwrap(),
+        RelayerApprovalUpdated {
+            relayer: relayer_bytes.clone(),
+            approved: true,
+        }
+    );
+    assert!(call(commit_header_range, relayer.clone(), &input));
+
+    // a removed relayer can not submit proofs.
+    let calldata = (relayer_bytes, false).abi_encode_params();
+    assert!(call(set_relayer_approval, guardian(), &calldata));
+    assert!(!call(commit_header_range, relayer, &input));
+}
+
+#[test]
+fn test_views() {
+    set_up();
+    assert_eq!(view::<u64>(latest_block, &[]), GENESIS_HEIGHT);
+    assert_eq!(view::<U256>(state_proof_nonce, &[]), U256::from(1));
+    assert!(!view::<bool>(frozen, &[]));
+    assert_eq!(
+        view::<Bytes>(guardian_view, &[]),
+        Bytes::from(guardian().as_bytes().to_vec())
+    );
+    assert_eq!(
+        view::<Vec<Bytes>>(role_members, &(GUARDIAN,).abi_encode_params()),
+        vec![Bytes::from(guardian().as_bytes().to_vec())]
+    );
+    assert_eq!(
+        view::<Bytes>(program_vkey_hash, &[]),
+        Bytes::from(vec![1; 32])
+    );
+    assert_eq!(
+        view::<FixedBytes<32>>(
+            block_height_to_header_hash,
+            &(GENESIS_HEIGHT,).abi_encode_params()
+        ),
+        GENESIS_HEADER
+    );
+
+    mock::set_verify_result(true);
+    let data_commitment_calldata = (U256::from(1),).abi_encode_params();
+    assert_eq!(
+        view::<FixedBytes<32>>(data_commitment, &data_commitment_calldata),
+        FixedBytes::ZERO
+    );
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::from([7; 32]),
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+    assert_eq!(view::<u64>(latest_block, &[]), GENESIS_HEIGHT + 10);
+    assert_eq!(view::<U256>(state_proof_nonce, &[]), U256::from(2));
+    assert_eq!(
+        view::<FixedBytes<32>>(data_commitment, &data_commitment_calldata),
+        FixedBytes::from([7; 32])
+    );
+    assert!(call(
+        update_freeze,
+        guardian(),
+        &(true,).abi_encode_params()
+    ));
+    assert!(view::<bool>(frozen, &[]));
+}
+
+#[test]
+fn test_commit_header_ranges() {
+    set_up();
+    mock::set_verify_result(true);
+    let targets = [
+        GENESIS_HEIGHT + 10,
+        GENESIS_HEIGHT + 20,
+        GENESIS_HEIGHT + 30,
+    ];
+    assert!(call(
+        commit_header_ranges,
+        Address::default(),
+        &commit_batch_input(&targets)
+    ));
+    assert_eq!(mock::verify_calls(), 3);
+
+    // every range is stored under the next proof nonce.
+    let events = mock::take_events();
+    assert_eq!(events.len(), 6);
+    let mut start_block = GENESIS_HEIGHT;
+    for (i, &target_block) in targets.iter().enumerate() {
+        assert_eq!(
+            DataCommitmentStored::decode_log_data(&events[2 * i + 1], true).unwrap(),
+            DataCommitmentStored {
+                proofNonce: U256::from(i + 1),
+                startBlock: start_block,
+                endBlock: target_block,
+                dataCommitment: FixedBytes::from([target_block as u8; 32]),
+            }
+        );
+        start_block = target_block;
+    }
+
+    // the next range starts at the last target block.
+    let input = commit_input(
+        FixedBytes::from([targets[2] as u8; 32]),
+        targets[2],
+        targets[2] + 10,
+        FixedBytes::ZERO,
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+}
+
+#[test]
+fn test_commit_header_ranges_all_or_nothing() {
+    set_up();
+    mock::set_verify_result(true);
+    assert!(!call(
+        commit_header_ranges,
+        Address::default(),
+        &(Vec::<CommitHeaderRangeInput>::new(),).abi_encode_params()
+    ));
+    assert_eq!(revert_error(), Error::EmptyBatch(EmptyBatch {}));
+
+    // the second range does not start at the target of the first one.
+    let range = |trusted_header, trusted_block, target_block| CommitHeaderRangeInput {
+        proof: Bytes::from(vec![3; 32]),
+        publicValues: public_values(
+            trusted_header,
+            trusted_block,
+            target_block,
+            FixedBytes::ZERO,
+        ),
+    };
+    let input = (vec![
+        range(GENESIS_HEADER, GENESIS_HEIGHT, GENESIS_HEIGHT + 10),
+        range(GENESIS_HEADER, GENESIS_HEIGHT, GENESIS_HEIGHT + 20),
+    ],)
+        .abi_encode_params();
+    assert!(!call(commit_header_ranges, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::TrustedBlockMismatch(TrustedBlockMismatch {
+            latestBlock: GENESIS_HEIGHT + 10,
+            trustedBlock: GENESIS_HEIGHT,
+        })
+    );
+
+    // the valid first range is not committed either.
+    assert!(mock::take_events().is_empty());
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+}
+
+#[test]
+fn test_commit_header_range_input_over_64_kib() {
+    set_up();
+    mock::set_verify_result(true);
+    let public_values = public_values(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+    let input = (Bytes::from(vec![3; 70_000]), public_values).abi_encode_params();
+    assert!(call(commit_header_range, Address::default(), &input));
+}
+
+#[test]
+fn test_commit_header_range_rejects_invalid_ranges() {
+    set_up();
+    mock::set_verify_result(true);
+
+    // trusted header mismatch.
+    let input = commit_input(
+        FixedBytes::ZERO,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        FixedBytes::ZERO,
+    );
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::TrustedHeaderMismatch(TrustedHeaderMismatch {
+            expected: GENESIS_HEADER,
+            found: FixedBytes::ZERO,
+        })
+    );
+    // target block not after the latest block.
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT,
+        FixedBytes::ZERO,
+    );
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::TargetBlockNotInRange(TargetBlockNotInRange {
+            latestBlock: GENESIS_HEIGHT,
+            targetBlock: GENESIS_HEIGHT,
+        })
+    );
+    // target block too far.
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 1_001,
+        FixedBytes::ZERO,
+    );
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::TargetBlockTooFar(TargetBlockTooFar {
+            targetBlock: GENESIS_HEIGHT + 1_001,
+            maxBlock: GENESIS_HEIGHT + 1_000,
+        })
+    );
+
+    // no proof was verified.
+    assert_eq!(mock::verify_calls(), 0);
+}
+
+#[test]
+fn test_verify_attestation() {
+    set_up();
+    let tuple = DataRootTuple {
+        height: U256::from(GENESIS_HEIGHT),
+        dataRoot: FixedBytes::from([5; 32]),
+    };
+    let data_commitment = leaf_digest(tuple.abi_encode().into());
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        data_commitment,
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+
+    let proof = BinaryMerkleProof {
+        sideNodes: vec![],
+        key: U256::ZERO,
+        numLeaves: U256::from(1),
+    };
+    let calldata = (U256::from(1), tuple.clone(), proof.clone()).abi_encode_params();
+    assert!(call(verify_attestation, Address::default(), &calldata));
+    assert_eq!(mock::take_return_data(), true.abi_encode());
+
+    // no data commitment for the nonce.
+    let calldata = (U256::from(2), tuple.clone(), proof.clone()).abi_encode_params();
+    assert!(call(verify_attestation, Address::default(), &calldata));
+    assert_eq!(mock::take_return_data(), false.abi_encode());
+
+    // nonce after the latest proof nonce.
+    let calldata = (U256::from(3), tuple.clone(), proof).abi_encode_params();
+    assert!(call(verify_attestation, Address::default(), &calldata));
+    assert_eq!(mock::take_return_data(), false.abi_encode());
+
+    // side nodes of a larger tree.
+    let proof = BinaryMerkleProof {
+        sideNodes: vec![FixedBytes::from([1; 32])],
+        key: U256::ZERO,
+        numLeaves: U256::from(1),
+    };
+    let calldata = (U256::from(1), tuple, proof).abi_encode_params();
+    assert!(call(verify_attestation, Address::default(), &calldata));
+    assert_eq!(mock::take_return_data(), false.abi_encode());
+}
+
+#[test]
+fn test_verify_attestation_range() {
+    set_up();
+    let tuples: Vec<DataRootTuple> = (0..5)
+        .map(|i| DataRootTuple {
+            height: U256::from(GENESIS_HEIGHT + 1 + i),
+            dataRoot: FixedBytes::from([i as u8; 32]),
+        })
+        .collect();
+    let data: Vec<Bytes> = tuples
+        .iter()
+        .map(|tuple| tuple.abi_encode().into())
+        .collect();
+    let tree = MerkleTree::new(&data);
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        tree.root(),
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+
+    let range = |begin: u32, end: u32, proof: BinaryMerkleRangeProof| {
+        let tuples = tuples[begin as usize..end as usize].to_vec();
+        (U256::from(1), tuples, proof).abi_encode_params()
+    };
+    for (begin, end) in [(0, 5), (0, 1), (1, 4), (3, 5)] {
+        let proof = tree.prove_range(begin, end).unwrap();
+        assert!(view::<bool>(
+            verify_attestation_range,
+            &range(begin, end, proof)
+        ));
+    }
+    // the proof of another range.
+    let proof = tree.prove_range(1, 3).unwrap();
+    assert!(!view::<bool>(
+        verify_attestation_range,
+        &range(
+            2,
+            4,
+            BinaryMerkleRangeProof {
+                beginKey: U256::from(2),
+                endKey: U256::from(4),
+                ..proof
+            }
+        )
+    ));
+
+    let mut calldata = range(0, 5, tree.prove_range(0, 5).unwrap());
+    calldata[31] = 3;
+    assert!(!call(
+        verify_attestation_range,
+        Address::default(),
+        &calldata
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::InvalidProofNonce(InvalidProofNonce {
+            proofNonce: U256::from(3)
+        })
+    );
+}
+
+#[test]
+fn test_verify_attestation_multi() {
+    set_up();
+    let tuples: Vec<DataRootTuple> = (0..5)
+        .map(|i| DataRootTuple {
+            height: U256::from(GENESIS_HEIGHT + 1 + i),
+            dataRoot: FixedBytes::from([i as u8; 32]),
+        })
+        .collect();
+    let data: Vec<Bytes> = tuples
+        .iter()
+        .map(|tuple| tuple.abi_encode().into())
+        .collect();
+    let tree = MerkleTree::new(&data);
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        tree.root(),
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+
+    let multi = |keys: &[u32], proof: BinaryMerkleMultiproof| {
+        let tuples: Vec<DataRootTuple> = keys
+            .iter()
+            .map(|key| tuples[*key as usize].clone())
+            .collect();
+        (U256::from(1), tuples, proof).abi_encode_params()
+    };
+    for keys in [&[0, 1, 2, 3, 4][..], &[2], &[0, 4], &[1, 3, 4]] {
+        let proof = tree.prove_multi(keys).unwrap();
+        assert!(view::<bool>(verify_attestation_multi, &multi(keys, proof)));
+    }
+    // the proof of other keys.
+    let proof = tree.prove_multi(&[0, 2]).unwrap();
+    assert!(!view::<bool>(
+        verify_attestation_multi,
+        &multi(
+            &[1, 3],
+            BinaryMerkleMultiproof {
+                keys: vec![U256::from(1), U256::from(3)],
+                ..proof
+            }
+        )
+    ));
+
+    let mut calldata = multi(&[0, 4], tree.prove_multi(&[0, 4]).unwrap());
+    calldata[31] = 3;
+    assert!(!call(
+        verify_attestation_multi,
+        Address::default(),
+        &calldata
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::InvalidProofNonce(InvalidProofNonce {
+            proofNonce: U256::from(3)
+        })
+    );
+}
+
+#[test]
+fn test_verify_attestations() {
+    set_up();
+    mock::set_verify_result(true);
+    // two ranges with a single tuple each, the data commitment of a single leaf tree is its digest.
+    let tuples: Vec<DataRootTuple> = (1..=2)
+        .map(|i| DataRootTuple {
+            height: U256::from(GENESIS_HEIGHT + 10 * i),
+            dataRoot: FixedBytes::from([i as u8; 32]),
+        })
+        .collect();
+    for (i, tuple) in tuples.iter().enumerate() {
+        let trusted_block = GENESIS_HEIGHT + 10 * i as u64;
+        let trusted_header = if i == 0 {
+            GENESIS_HEADER
+        } else {
+            FixedBytes::from([trusted_block as u8; 32])
+        };
+        let input = commit_input(
+            trusted_header,
+            trusted_block,
+            trusted_block + 10,
+            leaf_digest(tuple.abi_encode().into()),
+        );
+        assert!(call(commit_header_range, Address::default(), &input));
+    }
+
+    let proof = BinaryMerkleProof {
+        sideNodes: vec![],
+        key: U256::ZERO,
+        numLeaves: U256::from(1),
+    };
+    let attestation = |nonce: u64, tuple: &DataRootTuple| VAInput {
+        proofNonce: U256::from(nonce),
+        tuple: tuple.clone(),
+        proof: proof.clone(),
+    };
+    let malformed = VAInput {
+        proof: BinaryMerkleProof {
+            sideNodes: vec![FixedBytes::from([1; 32])],
+            key: U256::from(1),
+            numLeaves: U256::from(1),
+        },
+        ..attestation(1, &tuples[0])
+    };
+    let inputs = vec![
+        attestation(1, &tuples[0]),
+        attestation(2, &tuples[1]),
+        // tuple of the other nonce.
+        attestation(1, &tuples[1]),
+        // nonce zero and the next nonce are invalid.
+        attestation(0, &tuples[0]),
+        attestation(3, &tuples[0]),
+        malformed,
+        attestation(2, &tuples[0]),
+        attestation(1, &tuples[0]),
+        attestation(2, &tuples[1]),
+    ];
+    let bitmap = view::<Bytes>(verify_attestations, &(inputs,).abi_encode_params());
+    assert_eq!(bitmap, Bytes::from(vec![0b1000_0011, 0b1]));
+
+    let empty: Vec<VAInput> = vec![];
+    assert!(!call(
+        verify_attestations,
+        Address::default(),
+        &(empty,).abi_encode_params()
+    ));
+    assert_eq!(revert_error(), Error::EmptyBatch(EmptyBatch {}));
+}
+
+/// Shares proof of the namespace 2 shares in a 2 x 4 square, the last two shares of the first
+/// row and the first two of the second, with the data root tuple committed under nonce 1.
+fn shares_proof() -> SharesProof {
+    let namespace = |id: u8| Namespace {
+        version: FixedBytes::from([0]),
+        id: FixedBytes::from([id; 28]),
+    };
+    let rows = [[1, 1, 2, 2], [2, 2, 3, 3]].map(|row| {
+        row.map(|id| (namespace(id), Bytes::from(vec![id; 512])))
+            .to_vec()
+    });
+    let row_leaves: Vec<Vec<_>> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(namespace, data)| nmt::leaf_digest(namespace, data))
+                .collect()
+        })
+        .collect();
+    let row_roots: Vec<_> = row_leaves
+        .iter()
+        .map(|leaves| nmt::root(leaves).unwrap())
+        .collect();
+
+    // the data root is the binary merkle tree of the row roots.
+    let row_digests: Vec<_> = row_roots
+        .iter()
+        .map(|row_root| leaf_digest(nmt::node_bytes(row_root)))
+        .collect();
+    let data_root = binary_merkle_tree::node_digest(row_digests[0], row_digests[1]);
+    let row_proofs = (0..2)
+        .map(|i| BinaryMerkleProof {
+            sideNodes: vec![row_digests[1 - i]],
+            key: U256::from(i),
+            numLeaves: U256::from(2),
+        })
+        .collect();
+
+    let tuple = DataRootTuple {
+        height: U256::from(GENESIS_HEIGHT + 5),
+        dataRoot: data_root,
+    };
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        leaf_digest(tuple.abi_encode().into()),
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+
+    SharesProof {
+        data: vec![
+            rows[0][2].1.clone(),
+            rows[0][3].1.clone(),
+            rows[1][0].1.clone(),
+            rows[1][1].1.clone(),
+        ],
+        shareProofs: vec![
+            nmt::prove(&row_leaves[0], 2, 4).unwrap(),
+            nmt::prove(&row_leaves[1], 0, 2).unwrap(),
+        ],
+        namespace: namespace(2),
+        rowRoots: row_roots,
+        rowProofs: row_proofs,
+        attestationProof: AttestationProof {
+            tupleRootNonce: U256::from(1),
+            tuple,
+            proof: BinaryMerkleProof {
+                sideNodes: vec![],
+                key: U256::ZERO,
+                numLeaves: U256::from(1),
+            },
+        },
+    }
+}
+
+#[test]
+fn test_verify_shares_to_data_root_tuple() {
+    set_up();
+    let proof = shares_proof();
+    let verify = |proof: &SharesProof| {
+        view::<bool>(
+            verify_shares_to_data_root_tuple,
+            &(proof.clone(),).abi_encode_params(),
+        )
+    };
+    assert!(verify(&proof));
+
+    // a share of another namespace.
+    let mut other = proof.clone();
+    other.data[3] = Bytes::from(vec![3; 512]);
+    assert!(!verify(&other));
+    // a row root that is not in the data root.
+    let mut other = proof.clone();
+    other.rowRoots.swap(0, 1);
+    assert!(!verify(&other));
+    // a data root tuple that is not attested.
+    let mut other = proof.clone();
+    other.attestationProof.tuple.height += U256::from(1);
+    assert!(!verify(&other));
+
+    let mut other = proof.clone();
+    other.rowProofs.pop();
+    assert!(!call(
+        verify_shares_to_data_root_tuple,
+        Address::default(),
+        &(other,).abi_encode_params()
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::RowProofsCountMismatch(RowProofsCountMismatch {
+            expected: U256::from(2),
+            found: U256::from(1),
+        })
+    );
+    let mut other = proof.clone();
+    other.data.pop();
+    assert!(!call(
+        verify_shares_to_data_root_tuple,
+        Address::default(),
+        &(other,).abi_encode_params()
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::SharesCountMismatch(SharesCountMismatch {
+            expected: U256::from(4),
+            found: U256::from(3),
+        })
+    );
+    let mut other = proof;
+    other.attestationProof.tupleRootNonce = U256::from(3);
+    assert!(!call(
+        verify_shares_to_data_root_tuple,
+        Address::default(),
+        &(other,).abi_encode_params()
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::InvalidProofNonce(InvalidProofNonce {
+            proofNonce: U256::from(3)
+        })
+    );
+}
+
+#[test]
+fn test_verify_shares_to_data_root_tuple_of_celestia_block() {
+    set_up();
+    // the shares of the blob of the namespace 0x01bb and the row roots are captured from a
+    // celestia-node, the data root is the binary merkle tree of the row and column roots.
+    let square = fixtures::extended_data_square();
+    let dah: Vec<Bytes> = square
+        .row_roots
+        .iter()
+        .chain(&square.column_roots)
+        .map(nmt::node_bytes)
+        .collect();
+    let data_root_tree = MerkleTree::new(&dah);
+    let tuple = DataRootTuple {
+        height: U256::from(GENESIS_HEIGHT + 5),
+        dataRoot: data_root_tree.root(),
+    };
+    mock::set_verify_result(true);
+    let input = commit_input(
+        GENESIS_HEADER,
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        leaf_digest(tuple.abi_encode().into()),
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+
+    let proof = SharesProof {
+        data: [&square.rows[0][3..4], &square.rows[1][0..4]].concat(),
+        shareProofs: vec![
+            nmt::prove(&square.row_leaves(0), 3, 4).unwrap(),
+            nmt::prove(&square.row_leaves(1), 0, 4).unwrap(),
+        ],
+        namespace: fixtures::share_namespace(&square.rows[1][0]),
+        rowRoots: square.row_roots[..2].to_vec(),
+        rowProofs: vec![
+            data_root_tree.prove(0).unwrap(),
+            data_root_tree.prove(1).unwrap(),
+        ],
+        attestationProof: AttestationProof {
+            tupleRootNonce: U256::from(1),
+            tuple,
+            proof: BinaryMerkleProof {
+                sideNodes: vec![],
+                key: U256::ZERO,
+                numLeaves: U256::from(1),
+            },
+        },
+    };
+    let verify = |proof: &SharesProof| {
+        view::<bool>(
+            verify_shares_to_data_root_tuple,
+            &(proof.clone(),).abi_encode_params(),
+        )
+    };
+    assert!(verify(&proof));
+
+    // the share before the blob is of the namespace 0x01aa.
+    let mut other = proof.clone();
+    other.data.insert(0, square.rows[0][2].clone());
+    other.shareProofs[0] = nmt::prove(&square.row_leaves(0), 2, 4).unwrap();
+    assert!(!verify(&other));
+}
+
+#[test]
+fn test_rejects_invalid_calldata() {
+    set_up();
+    assert!(!call(update_freeze, guardian(), &[]));
+    assert_eq!(revert_error(), Error::InvalidCalldata(InvalidCalldata {}));
+    assert!(!call(update_freeze, guardian(), &[2; 32]));
+    assert_eq!(revert_error(), Error::InvalidCalldata(InvalidCalldata {}));
+}
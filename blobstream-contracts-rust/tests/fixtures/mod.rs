@@ -0,0 +1,83 @@
+// Each test crate uses a part of the fixtures.
+#![allow(dead_code)]
+
+use blobstream_contracts_rust::input_type::{Namespace, NamespaceNode};
+use blobstream_contracts_rust::nmt::{leaf_digest, parity_namespace};
+use seq_wasm_sdk::{Bytes, FixedBytes};
+use std::str::FromStr;
+
+/// Data availability header and rows of an extended data square captured from a celestia-node.
+pub struct ExtendedDataSquare {
+    pub width: usize,
+    pub row_roots: Vec<NamespaceNode>,
+    pub column_roots: Vec<NamespaceNode>,
+    /// The first rows of the square, the shares of a row from left to right.
+    pub rows: Vec<Vec<Bytes>>,
+}
+
+impl ExtendedDataSquare {
+    /// Returns the leaves of the row, the shares of the original data have their own namespace
+    /// and the parity shares the parity namespace.
+    pub fn row_leaves(&self, row: usize) -> Vec<NamespaceNode> {
+        self.rows[row]
+            .iter()
+            .enumerate()
+            .map(|(column, share)| {
+                let namespace = if row < self.width / 2 && column < self.width / 2 {
+                    share_namespace(share)
+                } else {
+                    parity_namespace()
+                };
+                leaf_digest(&namespace, share)
+            })
+            .collect()
+    }
+}
+
+/// Returns the square of `extended_data_square.txt`.
+pub fn extended_data_square() -> ExtendedDataSquare {
+    let mut square = ExtendedDataSquare {
+        width: 0,
+        row_roots: Vec::new(),
+        column_roots: Vec::new(),
+        rows: Vec::new(),
+    };
+    let lines = include_str!("extended_data_square.txt")
+        .lines()
+        .filter(|line| !line.starts_with('#'));
+    for line in lines {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let data = Bytes::from_str(fields[fields.len() - 1]).unwrap();
+        match fields[0] {
+            "row_root" => square.row_roots.push(namespace_node(&data)),
+            "column_root" => square.column_roots.push(namespace_node(&data)),
+            "share" => {
+                let row: usize = fields[1].parse().unwrap();
+                if row == square.rows.len() {
+                    square.rows.push(Vec::new());
+                }
+                square.rows[row].push(data);
+            }
+            field => panic!("unknown fixture field {field}"),
+        }
+    }
+    square.width = square.row_roots.len();
+    square
+}
+
+/// Returns the namespace in the first 29 bytes of the share.
+pub fn share_namespace(share: &[u8]) -> Namespace {
+    Namespace {
+        version: FixedBytes::from_slice(&share[..1]),
+        id: FixedBytes::from_slice(&share[1..29]),
+    }
+}
+
+/// Returns the node of its min and max namespace followed by its digest.
+pub fn namespace_node(data: &[u8]) -> NamespaceNode {
+    NamespaceNode {
+        min: share_namespace(&data[..29]),
+        max: share_namespace(&data[29..58]),
+        digest: FixedBytes::from_slice(&data[58..]),
+    }
+}
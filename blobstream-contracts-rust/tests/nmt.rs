@@ -0,0 +1,146 @@
+use blobstream_contracts_rust::error::{Error, InvalidShareRange, SharesCountMismatch};
+use blobstream_contracts_rust::input_type::{Namespace, NamespaceNode};
+use blobstream_contracts_rust::nmt::{
+    leaf_digest, node_digest, parity_namespace, prove, root, verify_multi, verify_multi_hashes,
+};
+use seq_wasm_sdk::{Bytes, FixedBytes, U256};
+
+mod fixtures;
+
+fn namespace(id: u8) -> Namespace {
+    Namespace {
+        version: FixedBytes::new([0]),
+        id: FixedBytes::new([id; 28]),
+    }
+}
+
+/// Shares of the namespaces, one share per namespace.
+fn shares(ids: &[u8]) -> Vec<(Namespace, Bytes)> {
+    ids.iter()
+        .map(|&id| (namespace(id), Bytes::from(vec![id; 512])))
+        .collect()
+}
+
+fn leaves(shares: &[(Namespace, Bytes)]) -> Vec<NamespaceNode> {
+    shares
+        .iter()
+        .map(|(namespace, data)| leaf_digest(namespace, data))
+        .collect()
+}
+
+#[test]
+fn test_leaf_digest_namespace() {
+    let leaf = leaf_digest(&namespace(1), &[1; 512]);
+    assert_eq!(leaf.min, namespace(1));
+    assert_eq!(leaf.max, namespace(1));
+    assert_ne!(leaf.digest, leaf_digest(&namespace(2), &[1; 512]).digest);
+}
+
+#[test]
+fn test_node_digest_ignores_parity_namespace() {
+    let left = leaf_digest(&namespace(1), &[1]);
+    let right = leaf_digest(&namespace(2), &[2]);
+    let parity = leaf_digest(&parity_namespace(), &[3]);
+
+    let node = node_digest(&left, &right);
+    assert_eq!((node.min, node.max), (namespace(1), namespace(2)));
+    let node = node_digest(&left, &parity);
+    assert_eq!((node.min, node.max), (namespace(1), namespace(1)));
+    let node = node_digest(&parity, &parity);
+    assert_eq!(
+        (node.min, node.max),
+        (parity_namespace(), parity_namespace())
+    );
+}
+
+#[test]
+fn test_verify_multi_every_range() {
+    for size in 1..=9u8 {
+        let shares = shares(&(1..=size).collect::<Vec<u8>>());
+        let leaves = leaves(&shares);
+        let root = root(&leaves).unwrap();
+        for begin in 0..size as u32 {
+            for end in begin + 1..=size as u32 {
+                let proof = prove(&leaves, begin, end).unwrap();
+                let range = &leaves[begin as usize..end as usize];
+                assert!(
+                    verify_multi_hashes(&root, &proof, range).unwrap(),
+                    "size {size} range {begin}..{end}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_verify_multi_namespace() {
+    let shares = shares(&[1, 2, 2, 2, 3, 4, 4, 5]);
+    let leaves = leaves(&shares);
+    let root = root(&leaves).unwrap();
+    let proof = prove(&leaves, 1, 4).unwrap();
+    let data: Vec<Bytes> = shares[1..4].iter().map(|(_, data)| data.clone()).collect();
+    assert!(verify_multi(&root, &proof, &namespace(2), &data).unwrap());
+
+    // other namespace or data.
+    assert!(!verify_multi(&root, &proof, &namespace(3), &data).unwrap());
+    let mut other = data.clone();
+    other[1] = Bytes::from(vec![9; 512]);
+    assert!(!verify_multi(&root, &proof, &namespace(2), &other).unwrap());
+
+    // missing side node.
+    let mut short = proof.clone();
+    short.sideNodes.pop();
+    assert!(!verify_multi(&root, &short, &namespace(2), &data).unwrap());
+
+    assert_eq!(
+        verify_multi(&root, &proof, &namespace(2), &data[..2]),
+        Err(Error::SharesCountMismatch(SharesCountMismatch {
+            expected: U256::from(3),
+            found: U256::from(2),
+        }))
+    );
+    let mut empty = proof.clone();
+    empty.endKey = empty.beginKey;
+    assert_eq!(
+        verify_multi(&root, &empty, &namespace(2), &[]),
+        Err(Error::InvalidShareRange(InvalidShareRange {
+            beginKey: U256::from(1),
+            endKey: U256::from(1),
+        }))
+    );
+}
+
+#[test]
+fn test_celestia_row_roots() {
+    // roots computed by a celestia-node, they pin leaf_digest and node_digest with the parity
+    // shares of the second half of the rows.
+    let square = fixtures::extended_data_square();
+    for row in 0..square.rows.len() {
+        assert_eq!(
+            root(&square.row_leaves(row)).as_ref(),
+            Some(&square.row_roots[row]),
+            "row {row}"
+        );
+    }
+}
+
+#[test]
+fn test_celestia_multiproof() {
+    // the blob of the namespace 0x01bb starts at the last original share of row 0 and fills the
+    // original shares of row 1.
+    let square = fixtures::extended_data_square();
+    let namespace = fixtures::share_namespace(&square.rows[1][0]);
+    for (row, begin, end) in [(0, 3, 4), (1, 0, 4)] {
+        let leaves = square.row_leaves(row);
+        let proof = prove(&leaves, begin, end).unwrap();
+        let data = &square.rows[row][begin as usize..end as usize];
+        assert!(verify_multi(&square.row_roots[row], &proof, &namespace, data).unwrap());
+        let other_row = &square.row_roots[1 - row];
+        assert!(!verify_multi(other_row, &proof, &namespace, data).unwrap());
+    }
+
+    // a range over the namespaces 0x01aa, 0x01bb and parity shares.
+    let leaves = square.row_leaves(0);
+    let proof = prove(&leaves, 1, 6).unwrap();
+    assert!(verify_multi_hashes(&square.row_roots[0], &proof, &leaves[1..6]).unwrap());
+}
@@ -5,6 +5,7 @@ extern crate wee_alloc;
 pub use seq_wasm_sdk::allocator::{allocate, deallocate}; // re-export
 use seq_wasm_sdk::state::{self};
 use seq_wasm_sdk::types::Address;
+use seq_wasm_sdk::utils;
 use seq_wasm_sdk::{FixedBytes, U256};
 pub use std::alloc::{alloc, Layout};
 use std::vec;
@@ -85,12 +86,12 @@ pub extern "C" fn test_get_bytes32() -> bool {
 
 #[no_mangle]
 pub extern "C" fn test_store_bytes() {
-    state::store_vec(11, &vec![1, 2, 3]);
+    state::store_vec(11, &[1, 2, 3]);
 }
 
 #[no_mangle]
 pub extern "C" fn test_get_bytes() -> bool {
-    state::store_vec(12, &vec![1, 2, 3]);
+    state::store_vec(12, &[1, 2, 3]);
     let bytes_fs = state::get_vec(12);
     if bytes_fs == vec![1, 2, 3] {
         return true;
@@ -174,12 +175,21 @@ pub extern "C" fn test_get_mapping_bytes32_u32() -> bool {
     false
 }
 
+#[no_mangle]
+pub extern "C" fn test_set_return_data() -> bool {
+    // only the data set last is returned.
+    utils::set_return_data(&[1, 2, 3]);
+    utils::set_return_value(&(U256::from(123), true));
+    true
+}
+
 #[no_mangle]
 pub extern "C" fn test_multi_input(a: u32, b: u64, c: u32, d: u32) -> u32 {
     a + b as u32 + c + d
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn test_tx_context(tx_context_ptr: *const seq_wasm_sdk::utils::TxContext) -> u32 {
     let tx_ctx = unsafe {
         assert!(!tx_context_ptr.is_null());
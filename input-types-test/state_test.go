@@ -43,6 +43,7 @@ func TestState(t *testing.T) {
 	test_get_mapping_bytes32_bytes32 := mod.ExportedFunction("test_get_mapping_bytes32_bytes32")
 	test_store_mapping_bytes32_u32 := mod.ExportedFunction("test_store_mapping_bytes32_u32")
 	test_get_mapping_bytes32_u32 := mod.ExportedFunction("test_get_mapping_bytes32_u32")
+	test_set_return_data := mod.ExportedFunction("test_set_return_data")
 	test_multi_input := mod.ExportedFunction("test_multi_input")
 	test_tx_context := mod.ExportedFunction("test_tx_context")
 
@@ -139,6 +140,15 @@ func TestState(t *testing.T) {
 	require.NoError(t, err)
 	require.Equal(t, result[0], uint64(1))
 
+	// set return data
+	ok, returnData, err := callWithReturnData(ctxWasm, mapper, test_set_return_data)
+	require.NoError(t, err)
+	require.True(t, ok)
+	expected := make([]byte, 64)
+	expected[31] = 123
+	expected[63] = 1
+	require.Equal(t, expected, returnData)
+
 	result, err = test_multi_input.Call(ctxWasm, 1, 2, 4, 5)
 	require.NoError(t, err)
 	require.Equal(t, result[0], uint64(12))
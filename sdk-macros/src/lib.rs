@@ -1,24 +1,30 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 use quote::quote;
+use std::collections::HashMap;
 use syn::{
-    parse_macro_input, Attribute, Error, Fields, ItemFn, ItemStruct, ReturnType, Visibility,
+    parse_macro_input, Attribute, Error, Expr, ExprLit, Fields, FnArg, ItemFn, ItemStruct, Lit,
+    LitInt, MetaNameValue, ReturnType, Type, Visibility,
 };
 
 /// This macro is used to define a public function that can be called from the seq wasm runtime.
-/// The function must be declared as `pub fn function_name() {}` without any additional modifiers (unsafe, extern, const, async, etc.).
+/// The function must be declared as `pub fn function_name(..) -> T {}` without any additional modifiers (unsafe, extern, const, async, etc.) or generics.
 /// It will be exported with the same name as the function name.
 /// with the signature `pub extern "C" fn function_name(tx_context: *const TxContext, ptr: *const u8, len: u32) -> bool`.
-/// The function should return a boolean value indicating whether the function executed successfully.
+/// The parameters are ABI decoded from the calldata as a tuple, the call reverts with `InvalidCalldata()` if the calldata can not be decoded.
+/// The return value is ABI encoded and set as the return data of the call. A `Result` return type fails the call on `Err` and reverts with the error, which must be a `sol!` error enum.
+/// State writes of the function are staged and flushed to the host only if the call succeeds, a failed call leaves the state as it was.
 /// it will unpack msg_sender and block_time_stamp from the TxContext; these values can be used in the function body.
+/// `#[only_role(ROLE)]` below `#[public]` restricts the function to accounts with the role, a call from an account without the role reverts with `MissingRole(role, account)` before the calldata is decoded.
+/// `#[migrate(from = N)]` below `#[public]` makes it a migration of the state from layout version `N` to `N + 1`, a call on state with another layout version reverts with `LayoutVersionMismatch(expected, found)` before the calldata is decoded.
+/// The layout version is updated only if the function succeeds, the caller is not checked.
 #[proc_macro_attribute]
 pub fn public(_metadata: TokenStream, item: TokenStream) -> TokenStream {
     let input_fn = parse_macro_input!(item as ItemFn);
 
-    // Check if function declared is public, has no inputs, no return type, and no additional modifiers
+    // Check if function declared is public, has no generics, and no additional modifiers
     let is_valid = matches!(input_fn.vis, Visibility::Public(_))
-        && input_fn.sig.inputs.is_empty()
-        && matches!(input_fn.sig.output, ReturnType::Default)
+        && input_fn.sig.generics.params.is_empty()
         && input_fn.sig.unsafety.is_none()
         && input_fn.sig.abi.is_none()
         && input_fn.sig.constness.is_none()
@@ -26,14 +32,122 @@ pub fn public(_metadata: TokenStream, item: TokenStream) -> TokenStream {
 
     if !is_valid {
         // If the function doesn't meet the criteria, return an error
-        let error_message = "Function must be declared as `pub fn function_name() {}` without any additional modifiers (unsafe, extern, const, async, etc.)";
+        let error_message = "Function must be declared as `pub fn function_name(..) -> T {}` without any additional modifiers (unsafe, extern, const, async, etc.) or generics";
         return Error::new_spanned(input_fn.sig, error_message)
             .to_compile_error()
             .into();
     }
 
-    let function_name = input_fn.sig.ident;
-    let function_body = input_fn.block;
+    let mut arg_pats = Vec::new();
+    let mut arg_types = Vec::new();
+    for arg in &input_fn.sig.inputs {
+        match arg {
+            FnArg::Typed(pat_type) => {
+                arg_pats.push(&pat_type.pat);
+                arg_types.push(&pat_type.ty);
+            }
+            FnArg::Receiver(_) => {
+                return Error::new_spanned(arg, "Public function can not take `self`")
+                    .to_compile_error()
+                    .into();
+            }
+        }
+    }
+
+    // check the roles of `#[only_role(..)]` attributes, before the calldata is decoded.
+    let mut role_checks = Vec::new();
+    for attr in input_fn
+        .attrs
+        .iter()
+        .filter(|attr| is_attr(attr, "only_role"))
+    {
+        let role: Expr = match attr.parse_args() {
+            Ok(role) => role,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        role_checks.push(quote! {
+            if let Err(error) = ::seq_wasm_sdk::access_control::only_role(#role, &msg_sender) {
+                return ::seq_wasm_sdk::utils::revert(error);
+            }
+        });
+    }
+
+    // a migration runs only on state with its layout version, and moves the state to the next version when it succeeds.
+    let mut migrations = input_fn
+        .attrs
+        .iter()
+        .filter(|attr| is_attr(attr, "migrate"));
+    let (version_check, complete_migration) = match migrations.next() {
+        None => (quote!(), quote!()),
+        Some(attr) => {
+            if let Some(other) = migrations.next() {
+                return Error::new_spanned(other, "A function can only have one `#[migrate]`")
+                    .to_compile_error()
+                    .into();
+            }
+            let from = match parse_migrate_from(attr) {
+                Ok(from) => from,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            (
+                quote! {
+                    if let Err(error) = ::seq_wasm_sdk::migration::only_layout_version(#from) {
+                        return ::seq_wasm_sdk::utils::revert(error);
+                    }
+                },
+                quote!(::seq_wasm_sdk::migration::complete_migration(#from);),
+            )
+        }
+    };
+
+    // decode the calldata into the parameters, no calldata is read without parameters.
+    let decode_args = if arg_types.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            let calldata: &[u8] = if len == 0 {
+                &[]
+            } else {
+                unsafe { ::seq_wasm_sdk::slice::from_raw_parts(ptr, len as usize) }
+            };
+            let (#(#arg_pats,)*) = match <(#(#arg_types,)*) as ::seq_wasm_sdk::SolValue>::abi_decode_params(calldata, true) {
+                Ok(args) => args,
+                Err(_) => {
+                    let error = ::seq_wasm_sdk::utils::CallError::InvalidCalldata(::seq_wasm_sdk::utils::InvalidCalldata {});
+                    return ::seq_wasm_sdk::utils::revert(error);
+                }
+            };
+        }
+    };
+
+    let function_name = &input_fn.sig.ident;
+    let function_body = &input_fn.block;
+    let output = &input_fn.sig.output;
+    let set_return = match output {
+        ReturnType::Default => quote! {
+            let () = output;
+            #complete_migration
+            transaction.commit();
+            true
+        },
+        ReturnType::Type(_, ty) if is_result(ty) => quote! {
+            match output {
+                Ok(value) => {
+                    #complete_migration
+                    transaction.commit();
+                    ::seq_wasm_sdk::utils::set_return_value(&value);
+                    true
+                }
+                Err(error) => ::seq_wasm_sdk::utils::revert(error),
+            }
+        },
+        ReturnType::Type(..) => quote! {
+            #complete_migration
+            transaction.commit();
+            ::seq_wasm_sdk::utils::set_return_value(&output);
+            true
+        },
+    };
     let doc_attrs: Vec<&Attribute> = input_fn
         .attrs
         .iter()
@@ -43,15 +157,71 @@ pub fn public(_metadata: TokenStream, item: TokenStream) -> TokenStream {
         #(#doc_attrs)*
         #[cfg_attr(all(target_arch = "wasm32"), export_name = stringify!(#function_name))]
         #[no_mangle]
+        #[allow(clippy::redundant_closure_call, clippy::not_unsafe_ptr_arg_deref)]
         pub extern "C" fn #function_name(tx_context: *const TxContext, ptr: *const u8, len: u32) -> bool {
+            #[allow(unused_variables)]
             let tx_ctx = TxContext::unpack(tx_context);
+            #[allow(unused_variables)]
             let msg_sender = tx_ctx.msg_sender();
+            #[allow(unused_variables)]
             let block_time_stamp = tx_ctx.time_stamp();
-            #function_body
+            // state writes are staged and discarded unless the call succeeds.
+            let transaction = ::seq_wasm_sdk::state::Transaction::begin();
+            #(#role_checks)*
+            #version_check
+            #decode_args
+            let output = (|| #output #function_body)();
+            #set_return
         }
     })
 }
 
+/// Returns true if the last segment of the attribute path is `name`, so `#[seq_wasm_sdk_macros::public]` matches `public`.
+fn is_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
+
+/// Parses the layout version `N` of `#[migrate(from = N)]`.
+fn parse_migrate_from(attr: &Attribute) -> syn::Result<u32> {
+    let arg: MetaNameValue = attr.parse_args()?;
+    if !arg.path.is_ident("from") {
+        return Err(Error::new_spanned(&arg.path, "Expected `from = N`"));
+    }
+    let from: u32 = match &arg.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse()?,
+        value => {
+            return Err(Error::new_spanned(
+                value,
+                "Expected an integer layout version",
+            ))
+        }
+    };
+    if from == u32::MAX {
+        return Err(Error::new_spanned(
+            &arg.value,
+            "There is no layout version after `u32::MAX`",
+        ));
+    }
+    Ok(from)
+}
+
+/// Returns true if the type is a `Result`.
+fn is_result(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Result"),
+        _ => false,
+    }
+}
+
 #[proc_macro_attribute]
 pub fn input(_metadata: TokenStream, item: TokenStream) -> TokenStream {
     let input_struct = parse_macro_input!(item as ItemStruct);
@@ -74,7 +244,7 @@ pub fn input(_metadata: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(quote!(
         impl #struct_name {
             pub fn new(ptr: *const u8, len: u32) -> Self {
-                let input = unsafe { slice::from_raw_parts(ptr, (len as u16).into()) };
+                let input = unsafe { slice::from_raw_parts(ptr, len as usize) };
                 Self::abi_decode(input, true).unwrap()
             }
 
@@ -84,3 +254,181 @@ pub fn input(_metadata: TokenStream, item: TokenStream) -> TokenStream {
         }
     ))
 }
+
+/// This macro declares the state layout of a contract.
+/// It is applied to a struct with named fields of type `StorageValue<T>` or `StorageMap<K, V>`.
+/// Values are assigned static slots starting from 0, mappings are assigned ids starting from 1, in the order of declaration.
+/// A field can be pinned to a slot with `#[slot(n)]` or to a mapping id with `#[id(n)]`, the following fields continue from it.
+/// Two values sharing a slot, or two mappings sharing an id, are rejected at compile time.
+/// The struct is replaced by a unit struct with a `const fn` accessor per field, returning the typed handle.
+///
+/// ```
+/// use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+/// use seq_wasm_sdk::FixedBytes;
+/// use seq_wasm_sdk_macros::storage;
+///
+/// #[storage]
+/// struct Layout {
+///     latest_block: StorageValue<u64>,
+///     header_hashes: StorageMap<u64, FixedBytes<32>>,
+///     #[slot(5)]
+///     program_vkey: StorageValue<Vec<u8>>,
+/// }
+///
+/// assert_eq!(Layout::latest_block().slot(), 0);
+/// assert_eq!(Layout::header_hashes().id(), 1);
+/// assert_eq!(Layout::program_vkey().slot(), 5);
+/// ```
+///
+/// ```compile_fail
+/// use seq_wasm_sdk::storage::StorageValue;
+/// use seq_wasm_sdk_macros::storage;
+///
+/// #[storage]
+/// struct Layout {
+///     #[slot(1)]
+///     frozen: StorageValue<bool>,
+///     #[slot(1)]
+///     guardian: StorageValue<u32>,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn storage(_metadata: TokenStream, item: TokenStream) -> TokenStream {
+    let input_struct = parse_macro_input!(item as ItemStruct);
+    match expand_storage(input_struct) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// kind of a field in a `#[storage]` struct.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum StorageKind {
+    Value,
+    Map,
+}
+
+impl StorageKind {
+    fn of(ty: &Type) -> Option<Self> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+        match type_path.path.segments.last()?.ident.to_string().as_str() {
+            "StorageValue" => Some(Self::Value),
+            "StorageMap" => Some(Self::Map),
+            _ => None,
+        }
+    }
+
+    // attribute used to pin the field.
+    fn attr(self) -> &'static str {
+        match self {
+            Self::Value => "slot",
+            Self::Map => "id",
+        }
+    }
+
+    // first slot or id assigned.
+    fn first(self) -> u32 {
+        match self {
+            Self::Value => 0,
+            Self::Map => 1,
+        }
+    }
+}
+
+/// First slot and mapping id reserved for the sdk, same as `seq_wasm_sdk::storage::RESERVED_SLOT_START` and `RESERVED_ID_START`.
+const RESERVED_START: u32 = 0xFFFF_0000;
+
+fn expand_storage(input_struct: ItemStruct) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input_struct.fields {
+        Fields::Named(y) => &y.named,
+        _ => {
+            return Err(Error::new_spanned(
+                &input_struct,
+                "Storage struct must have named fields",
+            ))
+        }
+    };
+
+    // next slot or id per kind, and the field each assigned one belongs to.
+    let mut next = HashMap::from([
+        (StorageKind::Value, StorageKind::Value.first()),
+        (StorageKind::Map, StorageKind::Map.first()),
+    ]);
+    let mut assigned: HashMap<(StorageKind, u32), String> = HashMap::new();
+    let mut accessors = Vec::new();
+    for field in fields {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let kind = StorageKind::of(ty).ok_or_else(|| {
+            Error::new_spanned(
+                ty,
+                "Storage field must be a `StorageValue<T>` or `StorageMap<K, V>`",
+            )
+        })?;
+
+        let mut number = next[&kind];
+        for attr in &field.attrs {
+            if attr.path().is_ident("slot") || attr.path().is_ident("id") {
+                if !attr.path().is_ident(kind.attr()) {
+                    let message = match kind {
+                        StorageKind::Value => "Use `#[slot(n)]` to pin a StorageValue",
+                        StorageKind::Map => "Use `#[id(n)]` to pin a StorageMap",
+                    };
+                    return Err(Error::new_spanned(attr, message));
+                }
+                number = attr.parse_args::<LitInt>()?.base10_parse()?;
+            }
+        }
+
+        // slots and mapping ids from `RESERVED_START` are used by the sdk.
+        if number >= RESERVED_START {
+            let message = format!(
+                "`{}` uses {} {}, {}s from {:#X} are reserved for the sdk",
+                name,
+                kind.attr(),
+                number,
+                kind.attr(),
+                RESERVED_START
+            );
+            return Err(Error::new_spanned(name, message));
+        }
+        if let Some(other) = assigned.insert((kind, number), name.to_string()) {
+            let message = format!(
+                "`{}` uses {} {}, which is already assigned to `{}`",
+                name,
+                kind.attr(),
+                number,
+                other
+            );
+            return Err(Error::new_spanned(name, message));
+        }
+        next.insert(kind, number + 1);
+
+        let vis = &field.vis;
+        let doc_attrs: Vec<&Attribute> = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect();
+        accessors.push(quote! {
+            #(#doc_attrs)*
+            #vis const fn #name() -> #ty {
+                <#ty>::new(#number)
+            }
+        });
+    }
+
+    let struct_attrs = &input_struct.attrs;
+    let vis = &input_struct.vis;
+    let struct_name = &input_struct.ident;
+    Ok(quote! {
+        #(#struct_attrs)*
+        #vis struct #struct_name;
+
+        impl #struct_name {
+            #(#accessors)*
+        }
+    })
+}
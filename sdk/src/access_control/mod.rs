@@ -0,0 +1,185 @@
+use crate::events::Event;
+use crate::storage::{StorageMap, RESERVED_ID_START};
+use crate::{fixed_bytes, sol, types, FixedBytes};
+
+/// Identifier of a role, `keccak256` of the role name as in solidity `AccessControl`.
+pub type Role = FixedBytes<32>;
+
+/// Role allowed to grant and revoke every role.
+pub const ADMIN: Role = FixedBytes::ZERO;
+/// `keccak256("GUARDIAN_ROLE")`.
+pub const GUARDIAN: Role =
+    fixed_bytes!("55435dd261a4b9b3364963f7738a7a662ad9c84396d64be3365284bb7f0a5041");
+
+// members of a role, keyed by role and account.
+const MEMBERS: StorageMap<Vec<u8>, bool> = StorageMap::new(RESERVED_ID_START);
+// pending role transfers, keyed by role and the new account, holding the current account.
+const PENDING: StorageMap<Vec<u8>, types::Address> = StorageMap::new(RESERVED_ID_START + 1);
+// members of a role in order of granting, the concatenated address bytes.
+const MEMBER_LIST: StorageMap<Role, Vec<u8>> = StorageMap::new(RESERVED_ID_START + 2);
+
+sol! {
+    /// Errors and events of the access control functions.
+    #[derive(Debug, PartialEq, Eq)]
+    interface IAccessControl {
+        /// The account does not have the role.
+        error MissingRole(bytes32 role, bytes account);
+        /// No transfer of the role to the account is pending.
+        error NoPendingTransfer(bytes32 role, bytes account);
+        /// The bytes are not a valid address.
+        error InvalidAccount(bytes account);
+
+        /// Emitted when the role is granted to the account.
+        event RoleGranted(bytes32 indexed role, bytes account);
+        /// Emitted when the role is revoked from the account.
+        event RoleRevoked(bytes32 indexed role, bytes account);
+        /// Emitted when a transfer of the role is started, `to` has to accept it.
+        event RoleTransferStarted(bytes32 indexed role, bytes from, bytes to);
+    }
+}
+
+pub use IAccessControl::IAccessControlErrors as AccessControlError;
+pub use IAccessControl::{
+    InvalidAccount, MissingRole, NoPendingTransfer, RoleGranted, RoleRevoked, RoleTransferStarted,
+};
+
+/// Key of a role and account, the role followed by the address bytes.
+fn key(role: Role, account: &types::Address) -> Vec<u8> {
+    [role.as_slice(), account.as_bytes()].concat()
+}
+
+/// Returns true if the account has the role.
+pub fn has_role(role: Role, account: &types::Address) -> bool {
+    MEMBERS.get(key(role, account))
+}
+
+/// Returns the accounts with the role, in order of granting.
+pub fn role_members(role: Role) -> Vec<types::Address> {
+    MEMBER_LIST
+        .get(role)
+        .chunks_exact(types::Address::LEN)
+        .filter_map(types::Address::from_slice)
+        .collect()
+}
+
+/// Returns an error if the account does not have the role.
+pub fn only_role(role: Role, account: &types::Address) -> Result<(), AccessControlError> {
+    if !has_role(role, account) {
+        return Err(AccessControlError::MissingRole(MissingRole {
+            role,
+            account: account.as_bytes().to_vec().into(),
+        }));
+    }
+    Ok(())
+}
+
+/// Parses an account from calldata bytes.
+pub fn parse_account(bytes: &[u8]) -> Result<types::Address, AccessControlError> {
+    types::Address::from_slice(bytes).ok_or_else(|| {
+        AccessControlError::InvalidAccount(InvalidAccount {
+            account: bytes.to_vec().into(),
+        })
+    })
+}
+
+/// Grants the role to the account without any checks, e.g. while initializing a contract.
+pub fn grant_role_unchecked(role: Role, account: &types::Address) {
+    if has_role(role, account) {
+        return;
+    }
+    MEMBERS.insert(key(role, account), true);
+    let mut members = MEMBER_LIST.get(role);
+    members.extend_from_slice(account.as_bytes());
+    MEMBER_LIST.insert(role, members);
+    RoleGranted {
+        role,
+        account: account.as_bytes().to_vec().into(),
+    }
+    .emit();
+}
+
+/// Revokes the role from the account without any checks.
+pub fn revoke_role_unchecked(role: Role, account: &types::Address) {
+    if !has_role(role, account) {
+        return;
+    }
+    MEMBERS.remove(key(role, account));
+    let members: Vec<u8> = role_members(role)
+        .iter()
+        .filter(|member| *member != account)
+        .flat_map(|member| member.as_bytes().to_vec())
+        .collect();
+    MEMBER_LIST.insert(role, members);
+    RoleRevoked {
+        role,
+        account: account.as_bytes().to_vec().into(),
+    }
+    .emit();
+}
+
+/// Grants the role to the account. `caller` must be an admin.
+pub fn grant_role(
+    caller: &types::Address,
+    role: Role,
+    account: &types::Address,
+) -> Result<(), AccessControlError> {
+    only_role(ADMIN, caller)?;
+    grant_role_unchecked(role, account);
+    Ok(())
+}
+
+/// Revokes the role from the account. `caller` must be an admin.
+pub fn revoke_role(
+    caller: &types::Address,
+    role: Role,
+    account: &types::Address,
+) -> Result<(), AccessControlError> {
+    only_role(ADMIN, caller)?;
+    revoke_role_unchecked(role, account);
+    Ok(())
+}
+
+/// Revokes the role from `caller` itself.
+pub fn renounce_role(caller: &types::Address, role: Role) -> Result<(), AccessControlError> {
+    only_role(role, caller)?;
+    revoke_role_unchecked(role, caller);
+    Ok(())
+}
+
+/// Starts the transfer of the role from `caller` to `to`, which takes effect once `to` accepts it.
+/// A pending transfer to `to` is replaced.
+pub fn transfer_role(
+    caller: &types::Address,
+    role: Role,
+    to: &types::Address,
+) -> Result<(), AccessControlError> {
+    only_role(role, caller)?;
+    PENDING.insert(key(role, to), caller.clone());
+    RoleTransferStarted {
+        role,
+        from: caller.as_bytes().to_vec().into(),
+        to: to.as_bytes().to_vec().into(),
+    }
+    .emit();
+    Ok(())
+}
+
+/// Accepts the pending transfer of the role to `caller`. The role is revoked from the account that started
+/// the transfer and granted to `caller`.
+pub fn accept_role(caller: &types::Address, role: Role) -> Result<(), AccessControlError> {
+    let from = match PENDING.try_get(key(role, caller)) {
+        Ok(Some(from)) => from,
+        _ => {
+            return Err(AccessControlError::NoPendingTransfer(NoPendingTransfer {
+                role,
+                account: caller.as_bytes().to_vec().into(),
+            }))
+        }
+    };
+    // a transfer started by an account that lost the role can not be accepted.
+    only_role(role, &from)?;
+    PENDING.remove(key(role, caller));
+    revoke_role_unchecked(role, &from);
+    grant_role_unchecked(role, caller);
+    Ok(())
+}
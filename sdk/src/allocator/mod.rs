@@ -1,22 +1,80 @@
 use crate::MaybeUninit;
+use std::ops::Deref;
 
+// native builds, e.g. tests, use the system allocator.
+#[cfg(target_arch = "wasm32")]
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 /// Allocates size bytes and leaks the pointer where they start.
+/// The host allocates the buffers it returns with this function, they are released with `deallocate`.
 #[cfg_attr(all(target_arch = "wasm32"), export_name = "allocate_ptr")]
-#[no_mangle]
+#[cfg_attr(not(target_arch = "wasm32"), no_mangle)]
 pub extern "C" fn allocate(size: usize) -> *mut u8 {
     // Allocate the amount of bytes needed.
-    let vec: Vec<MaybeUninit<u8>> = Vec::with_capacity(size);
+    let mut vec: Vec<MaybeUninit<u8>> = Vec::with_capacity(size);
+    // the length has to be the capacity, into_boxed_slice shrinks the allocation to the length.
+    unsafe { vec.set_len(size) };
 
     // into_raw leaks the memory to the caller.
     Box::into_raw(vec.into_boxed_slice()) as *mut u8
 }
 
 /// Deallocates size bytes at the pointer.
+///
+/// # Safety
+///
+/// `ptr` must have been returned by `allocate` with the same `size`.
 #[cfg_attr(all(target_arch = "wasm32"), export_name = "deallocate_ptr")]
-#[no_mangle]
+#[cfg_attr(not(target_arch = "wasm32"), no_mangle)]
 pub unsafe extern "C" fn deallocate(ptr: *mut u8, size: usize) {
     let _ = Vec::from_raw_parts(ptr, 0, size);
 }
+
+/// Bytes returned by the host in a buffer it allocated with `allocate`, the buffer is deallocated on drop.
+#[derive(Debug)]
+pub struct HostBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl HostBuffer {
+    /// Takes ownership of the `len` bytes at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by `allocate` with `len` as size, and `len` bytes must have been written to it.
+    /// If `len` is 0, `ptr` is not used.
+    pub unsafe fn from_raw_parts(ptr: *mut u8, len: usize) -> Self {
+        HostBuffer { ptr, len }
+    }
+
+    /// Returns the bytes as a `Vec` without copying them, the `Vec` owns the buffer.
+    pub fn into_vec(self) -> Vec<u8> {
+        let buffer = std::mem::ManuallyDrop::new(self);
+        if buffer.len == 0 {
+            return Vec::new();
+        }
+        // `allocate` made the buffer with the layout of a Vec with capacity `len`.
+        unsafe { Vec::from_raw_parts(buffer.ptr, buffer.len, buffer.len) }
+    }
+}
+
+impl Deref for HostBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { crate::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl Drop for HostBuffer {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe { deallocate(self.ptr, self.len) };
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::SolEvent;
+
+// Extern linked to wasm module as `env`. The function is implemented in the go runtime for wasm.
+#[cfg(not(feature = "mock-host"))]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Emits an event of the current call.
+    /// Takes a pointer to the topics, 32 bytes each, and a pointer to the data. The host copies the bytes.
+    #[link_name = "emitEvent"]
+    pub fn emit_event_inner(topics_ptr: u32, topics_size: u32, data_ptr: u32, data_size: u32);
+}
+
+/// Emits the event with Ethereum style topics and data.
+/// The first topic is the event signature hash followed by the indexed parameters, the data is the ABI encoded non indexed parameters.
+/// In a transaction the event is staged and sent to the host when the transaction commits.
+pub fn emit_event<E: SolEvent>(event: &E) {
+    let log = event.encode_log_data();
+    let topics: Vec<u8> = log.topics().iter().flat_map(|topic| topic.0).collect();
+    crate::state::emit_event(topics, log.data.to_vec());
+}
+
+/// Sends the event to the host.
+pub(crate) fn host_emit_event(topics: &[u8], data: &[u8]) {
+    #[cfg(not(feature = "mock-host"))]
+    unsafe {
+        emit_event_inner(
+            topics.as_ptr() as u32,
+            topics.len() as u32,
+            data.as_ptr() as u32,
+            data.len() as u32,
+        )
+    };
+    #[cfg(feature = "mock-host")]
+    crate::mock::emit_event(topics, data);
+}
+
+/// Emits events declared with `sol!`.
+pub trait Event: SolEvent {
+    /// Emits the event, see `emit_event`.
+    fn emit(&self) {
+        emit_event(self);
+    }
+}
+
+impl<T: SolEvent> Event for T {}
@@ -3,17 +3,24 @@ pub extern crate alloc;
 pub extern crate core;
 pub extern crate wee_alloc;
 
+pub mod access_control;
 pub mod allocator;
+pub mod events;
+pub mod migration;
+#[cfg(feature = "mock-host")]
+pub mod mock;
 pub mod precompiles;
 pub mod state;
+pub mod storage;
 pub mod types;
 pub mod utils;
 // alloy re-exports
 pub use alloy_primitives::{
-    bytes, fixed_bytes, hex::FromHex, keccak256, Address, Bytes, FixedBytes, Uint, B256, U256, U64,
+    bytes, fixed_bytes, hex::FromHex, keccak256, Address, Bytes, FixedBytes, LogData, Uint, B256,
+    U256, U64,
 };
 pub use alloy_sol_macro::sol;
-pub use alloy_sol_types::{SolType, SolValue};
+pub use alloy_sol_types::{SolError, SolEvent, SolInterface, SolType, SolValue};
 
 // std lib re-exports
 pub use core::slice;
@@ -0,0 +1,55 @@
+use crate::events::Event;
+use crate::sol;
+use crate::storage::{StorageValue, RESERVED_SLOT_START};
+
+// layout version of the contract state, absent for state written before versioning.
+const LAYOUT_VERSION: StorageValue<u32> = StorageValue::new(RESERVED_SLOT_START);
+
+sol! {
+    /// Errors and events of the layout migrations.
+    #[derive(Debug, PartialEq, Eq)]
+    interface IMigration {
+        /// The state has another layout version than the call expects.
+        error LayoutVersionMismatch(uint32 expected, uint32 found);
+
+        /// Emitted when a migration moved the state to a new layout version.
+        event LayoutMigrated(uint32 fromVersion, uint32 toVersion);
+    }
+}
+
+pub use IMigration::IMigrationErrors as MigrationError;
+pub use IMigration::{LayoutMigrated, LayoutVersionMismatch};
+
+/// Returns the layout version of the contract state, 0 if the state was written before versioning.
+pub fn layout_version() -> u32 {
+    LAYOUT_VERSION.get()
+}
+
+/// Sets the layout version, e.g. to the current version while initializing a contract.
+pub fn set_layout_version(version: u32) {
+    LAYOUT_VERSION.set(version);
+}
+
+/// Returns an error if the state does not have the layout version.
+pub fn only_layout_version(version: u32) -> Result<(), MigrationError> {
+    let found = layout_version();
+    if found != version {
+        return Err(MigrationError::LayoutVersionMismatch(
+            LayoutVersionMismatch {
+                expected: version,
+                found,
+            },
+        ));
+    }
+    Ok(())
+}
+
+/// Moves the state from layout version `from` to `from + 1`, called by `#[migrate(from = N)]` after the migration succeeded.
+pub fn complete_migration(from: u32) {
+    set_layout_version(from + 1);
+    LayoutMigrated {
+        fromVersion: from,
+        toVersion: from + 1,
+    }
+    .emit();
+}
@@ -0,0 +1,185 @@
+//! In-process host replacing the `env` and `precompiles` imports of the go runtime.
+//!
+//! Enabled with the `mock-host` feature, so that contract functions can be called directly from
+//! `cargo test`. Every thread has its own host, so tests running in parallel do not share state.
+
+use crate::utils::TxContext;
+use crate::{types, LogData, B256};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// State and precompile behaviour of the mock host.
+#[derive(Debug, Default)]
+struct MockHost {
+    // static slots.
+    statics: HashMap<u32, Vec<u8>>,
+    // dynamic slots keyed by (mapping id, key).
+    dynamics: HashMap<(u32, Vec<u8>), Vec<u8>>,
+    // balances keyed by (address, asset).
+    balances: HashMap<(Vec<u8>, Vec<u8>), u64>,
+    // result returned by `gnark_verify`.
+    verify_result: bool,
+    // number of `gnark_verify` calls.
+    verify_calls: usize,
+    // data set by the last `set_return_data` call.
+    return_data: Vec<u8>,
+    // data set by the last `revert` call.
+    revert_data: Vec<u8>,
+    // events emitted since the last `take_events` call.
+    events: Vec<LogData>,
+    // msg_senders registered by `TxContextBuilder`, indexed by handle.
+    senders: Vec<types::Address>,
+}
+
+thread_local! {
+    static HOST: RefCell<MockHost> = RefCell::new(MockHost::default());
+}
+
+/// Clears all state, balances and verify settings of the host. Registered msg_senders are kept.
+pub fn reset() {
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        let senders = std::mem::take(&mut host.senders);
+        *host = MockHost {
+            senders,
+            ..Default::default()
+        };
+    });
+}
+
+/// Sets the result returned by every following `gnark_verify` call. Defaults to false.
+pub fn set_verify_result(valid: bool) {
+    HOST.with(|host| host.borrow_mut().verify_result = valid);
+}
+
+/// Returns the number of `gnark_verify` calls made since the last `reset`.
+pub fn verify_calls() -> usize {
+    HOST.with(|host| host.borrow().verify_calls)
+}
+
+/// Returns the data set by the last `set_return_data` call and clears it.
+pub fn take_return_data() -> Vec<u8> {
+    HOST.with(|host| std::mem::take(&mut host.borrow_mut().return_data))
+}
+
+pub(crate) fn set_return_data(data: &[u8]) {
+    HOST.with(|host| host.borrow_mut().return_data = data.to_vec());
+}
+
+/// Returns the data set by the last `revert` call and clears it.
+pub fn take_revert_data() -> Vec<u8> {
+    HOST.with(|host| std::mem::take(&mut host.borrow_mut().revert_data))
+}
+
+pub(crate) fn set_revert_data(data: &[u8]) {
+    HOST.with(|host| host.borrow_mut().revert_data = data.to_vec());
+}
+
+/// Returns the events emitted since the last `take_events` call, in order of emission, and clears them.
+pub fn take_events() -> Vec<LogData> {
+    HOST.with(|host| std::mem::take(&mut host.borrow_mut().events))
+}
+
+pub(crate) fn emit_event(topics: &[u8], data: &[u8]) {
+    let topics = topics.chunks(32).map(B256::from_slice).collect();
+    let log = LogData::new_unchecked(topics, data.to_vec().into());
+    HOST.with(|host| host.borrow_mut().events.push(log));
+}
+
+pub(crate) fn store_bytes(slot: u32, value: Vec<u8>) {
+    HOST.with(|host| host.borrow_mut().statics.insert(slot, value));
+}
+
+pub(crate) fn get_bytes(slot: u32) -> Vec<u8> {
+    HOST.with(|host| {
+        host.borrow()
+            .statics
+            .get(&slot)
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) fn store_dynamic_bytes(id: u32, key: Vec<u8>, value: Vec<u8>) {
+    HOST.with(|host| host.borrow_mut().dynamics.insert((id, key), value));
+}
+
+pub(crate) fn get_dynamic_bytes(id: u32, key: Vec<u8>) -> Vec<u8> {
+    HOST.with(|host| {
+        host.borrow()
+            .dynamics
+            .get(&(id, key))
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) fn gnark_verify(_data: &[u8]) -> u32 {
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        host.verify_calls += 1;
+        host.verify_result as u32
+    })
+}
+
+pub(crate) fn set_balance(address: &[u8], asset: &[u8], amount: u64) {
+    HOST.with(|host| {
+        host.borrow_mut()
+            .balances
+            .insert((address.to_vec(), asset.to_vec()), amount)
+    });
+}
+
+pub(crate) fn get_balance(address: &[u8], asset: &[u8]) -> u64 {
+    HOST.with(|host| {
+        host.borrow()
+            .balances
+            .get(&(address.to_vec(), asset.to_vec()))
+            .copied()
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) fn msg_sender(handle: u32) -> types::Address {
+    HOST.with(|host| {
+        host.borrow()
+            .senders
+            .get(handle as usize)
+            .cloned()
+            .expect("msg_sender not registered, build the TxContext with TxContext::builder()")
+    })
+}
+
+/// Builder for a `TxContext` whose msg_sender is resolved by the mock host.
+#[derive(Debug, Default)]
+pub struct TxContextBuilder {
+    time_stamp: i64,
+    msg_sender: types::Address,
+}
+
+impl TxContextBuilder {
+    /// Sets the msg_sender(caller) of the transaction.
+    pub fn msg_sender(mut self, msg_sender: types::Address) -> Self {
+        self.msg_sender = msg_sender;
+        self
+    }
+
+    /// Sets the time_stamp of the block, the transaction is included in.
+    pub fn time_stamp(mut self, time_stamp: i64) -> Self {
+        self.time_stamp = time_stamp;
+        self
+    }
+
+    /// Registers the msg_sender with the host and returns the TxContext.
+    pub fn build(self) -> TxContext {
+        let msg_sender_ptr = HOST.with(|host| {
+            let mut host = host.borrow_mut();
+            host.senders.push(self.msg_sender);
+            (host.senders.len() - 1) as u32
+        });
+        TxContext {
+            time_stamp: self.time_stamp,
+            msg_sender_ptr,
+        }
+    }
+}
@@ -3,6 +3,8 @@ use crate::utils::gnarkPrecompileInputs;
 use crate::SolValue;
 
 // Extern linked to wasm module as `precompiles`. The functions are implemented in the go runtime for wasm.
+// Pointers passed to the host are borrowed, the host copies the bytes before returning.
+#[cfg(not(feature = "mock-host"))]
 #[link(wasm_import_module = "precompiles")]
 extern "C" {
     /// SP1 plonk verify precompile.
@@ -14,11 +16,11 @@ extern "C" {
     pub fn gnark_verify_inner(ptr: u32, size: u32) -> u32;
 
     /// Set balance of an address for an asset.
-    /// Takes pointer to the address and asset. Amount is u64.
+    /// Takes pointer to the address (`Address::LEN` bytes) and asset (`ID::LEN` bytes). Amount is u64.
     #[link_name = "setBalance"]
     pub fn set_balance_inner(address_ptr: u32, asset_ptr: u32, amount: u64);
     /// Get balance of an address for an asset.
-    /// Takes pointer to the address and asset. Returns amount as u64.
+    /// Takes pointer to the address (`Address::LEN` bytes) and asset (`ID::LEN` bytes). Returns amount as u64.
     #[link_name = "getBalance"]
     pub fn get_balance_inner(address_ptr: u32, asset_ptr: u32) -> u64;
 }
@@ -39,32 +41,37 @@ pub fn gnark_verify(
     }
     .abi_encode();
 
+    #[cfg(not(feature = "mock-host"))]
     let valid = unsafe { gnark_verify_inner(data.as_ptr() as u32, data.len() as u32) };
-    if valid == 1 {
-        true
-    } else {
-        false
-    }
+    #[cfg(feature = "mock-host")]
+    let valid = crate::mock::gnark_verify(&data);
+    valid == 1
 }
 
 /// Set balance of an `address` for an `asset`.
+#[cfg(not(feature = "mock-host"))]
 pub fn set_balance(address: types::Address, asset: types::ID, amount: u64) {
-    let address = address.as_bytes().to_vec();
-    let addr_ptr = address.as_ptr() as u32;
-    std::mem::forget(address);
-    let asset = asset.as_bytes().to_vec();
-    let asset_ptr = asset.as_ptr() as u32;
-    std::mem::forget(asset);
+    let addr_ptr = address.as_bytes().as_ptr() as u32;
+    let asset_ptr = asset.as_bytes().as_ptr() as u32;
     unsafe { set_balance_inner(addr_ptr, asset_ptr, amount) };
 }
 
 /// Get balance of an `address` for an `asset`.
+#[cfg(not(feature = "mock-host"))]
 pub fn get_balance(address: types::Address, asset: types::ID) -> u64 {
-    let address = address.as_bytes().to_vec();
-    let addr_ptr = address.as_ptr() as u32;
-    std::mem::forget(address);
-    let asset = asset.as_bytes().to_vec();
-    let asset_ptr = asset.as_ptr() as u32;
-    std::mem::forget(asset);
+    let addr_ptr = address.as_bytes().as_ptr() as u32;
+    let asset_ptr = asset.as_bytes().as_ptr() as u32;
     unsafe { get_balance_inner(addr_ptr, asset_ptr) }
 }
+
+/// Set balance of an `address` for an `asset`.
+#[cfg(feature = "mock-host")]
+pub fn set_balance(address: types::Address, asset: types::ID, amount: u64) {
+    crate::mock::set_balance(address.as_bytes(), asset.as_bytes(), amount);
+}
+
+/// Get balance of an `address` for an `asset`.
+#[cfg(feature = "mock-host")]
+pub fn get_balance(address: types::Address, asset: types::ID) -> u64 {
+    crate::mock::get_balance(address.as_bytes(), asset.as_bytes())
+}
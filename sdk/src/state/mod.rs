@@ -1,17 +1,50 @@
-use crate::types;
-use crate::{slice, FixedBytes, SolValue, U256};
+#[cfg(not(feature = "mock-host"))]
+use crate::allocator::HostBuffer;
+use crate::{events, types};
+use crate::{FixedBytes, SolValue, U256};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Error returned by the `try_get_*` functions when the stored bytes can not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateError {
+    /// The stored value does not have the length of the requested type.
+    InvalidLength { expected: usize, found: usize },
+    /// The stored value has the expected length, but is not a valid encoding of the requested type.
+    InvalidEncoding,
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::InvalidLength { expected, found } => {
+                write!(
+                    f,
+                    "invalid length: expected {expected} bytes, found {found}"
+                )
+            }
+            StateError::InvalidEncoding => write!(f, "invalid encoding"),
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
 
 // Extern linked to wasm module as `env`. The functions are implemented in the go runtime for wasm.
+// Pointers passed to the host are borrowed, the host copies the bytes before returning.
+// Bytes returned by the host are written to a buffer it allocates with `allocate`, owned by the contract.
+#[cfg(not(feature = "mock-host"))]
 #[link(wasm_import_module = "env")]
 extern "C" {
     /// Store bytes in the state at `slot`.
     /// Takes a pointer to the bytes and the size of the bytes to be stored in state.
-    /// Any ptr passed should be forgotten with `std::mem::forget()` before calling this function.`
     #[link_name = "stateStoreBytes"]
     pub fn store_bytes(slot: u32, ptr: u32, size: u32);
 
     /// Get bytes from the state at `slot`.
     /// Returns a u64 where the upper 32 bits are the pointer to the bytes and the lower 32 bits are the size of the bytes.
+    /// The bytes are released with `deallocate`, e.g. by wrapping them in a `HostBuffer`.
     #[link_name = "stateGetBytes"]
     pub fn get_bytes(slot: u32) -> u64;
 
@@ -22,108 +55,430 @@ extern "C" {
 
     /// Get bytes from the state at `id` and `key`.
     /// Returns a u64 where the upper 32 bits are the pointer to the bytes and the lower 32 bits are the size of the bytes.
+    /// The bytes are released with `deallocate`, e.g. by wrapping them in a `HostBuffer`.
     #[link_name = "stateGetDynamicBytes"]
     pub fn get_dynamic_bytes(id: u32, ptr_of_key: u32, size_of_key: u32) -> u64;
 }
 
-/// Stores a u256 value in the state at `key`.
-pub fn store_u256(key: u32, value: U256) {
-    let value_bytes = value.to_be_bytes_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
+/// Splits a u64 returned by the host into the pointer (upper 32 bits) and the size (lower 32 bits) of the bytes.
+#[cfg_attr(feature = "mock-host", allow(dead_code))]
+fn unpack_ptr(ptr_packed: u64) -> (*mut u8, usize) {
+    (
+        (ptr_packed >> 32) as u32 as *mut u8,
+        ptr_packed as u32 as usize,
+    )
+}
+
+/// Takes ownership of the bytes returned by the host as a packed pointer.
+#[cfg(not(feature = "mock-host"))]
+fn host_buffer(ptr_packed: u64) -> Vec<u8> {
+    let (ptr, size) = unpack_ptr(ptr_packed);
+    unsafe { HostBuffer::from_raw_parts(ptr, size) }.into_vec()
+}
+
+/// Stores `value` in the static storage `slot` through the host.
+#[cfg(not(feature = "mock-host"))]
+fn host_write_static(slot: u32, value: &[u8]) {
+    unsafe { store_bytes(slot, value.as_ptr() as u32, value.len() as u32) };
+}
+
+/// Returns the bytes stored in the static storage `slot` from the host.
+#[cfg(not(feature = "mock-host"))]
+fn host_read_static(slot: u32) -> Vec<u8> {
+    host_buffer(unsafe { get_bytes(slot) })
+}
+
+/// Stores `value` at `key` of the mapping `id` through the host.
+#[cfg(not(feature = "mock-host"))]
+fn host_write_dynamic(id: u32, key: &[u8], value: &[u8]) {
     unsafe {
-        store_bytes(key, ptr, len);
+        store_dynamic_bytes(
+            id,
+            key.as_ptr() as u32,
+            key.len() as u32,
+            value.as_ptr() as u32,
+            value.len() as u32,
+        )
+    };
+}
+
+/// Returns the bytes stored at `key` of the mapping `id` from the host.
+#[cfg(not(feature = "mock-host"))]
+fn host_read_dynamic(id: u32, key: &[u8]) -> Vec<u8> {
+    host_buffer(unsafe { get_dynamic_bytes(id, key.as_ptr() as u32, key.len() as u32) })
+}
+
+#[cfg(feature = "mock-host")]
+fn host_write_static(slot: u32, value: &[u8]) {
+    crate::mock::store_bytes(slot, value.to_vec());
+}
+
+#[cfg(feature = "mock-host")]
+fn host_read_static(slot: u32) -> Vec<u8> {
+    crate::mock::get_bytes(slot)
+}
+
+#[cfg(feature = "mock-host")]
+fn host_write_dynamic(id: u32, key: &[u8], value: &[u8]) {
+    crate::mock::store_dynamic_bytes(id, key.to_vec(), value.to_vec());
+}
+
+#[cfg(feature = "mock-host")]
+fn host_read_dynamic(id: u32, key: &[u8]) -> Vec<u8> {
+    crate::mock::get_dynamic_bytes(id, key.to_vec())
+}
+
+/// A static slot, or a key of a mapping.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Slot {
+    Static(u32),
+    Dynamic(u32, Vec<u8>),
+}
+
+impl Slot {
+    fn host_read(&self) -> Vec<u8> {
+        match self {
+            Slot::Static(slot) => host_read_static(*slot),
+            Slot::Dynamic(id, key) => host_read_dynamic(*id, key),
+        }
+    }
+
+    fn host_write(&self, value: &[u8]) {
+        match self {
+            Slot::Static(slot) => host_write_static(*slot, value),
+            Slot::Dynamic(id, key) => host_write_dynamic(*id, key, value),
+        }
+    }
+}
+
+/// Value of a slot in the cache.
+#[derive(Debug)]
+struct Entry {
+    value: Vec<u8>,
+    // true if the value is a staged write, false if it was read from the host.
+    dirty: bool,
+}
+
+/// Slots read and written and events emitted by the open transaction, the dirty slots and the events
+/// are flushed to the host on commit.
+#[derive(Debug, Default)]
+struct StateCache {
+    // number of open transactions, the cache is used while it is not zero.
+    depth: u32,
+    entries: BTreeMap<Slot, Entry>,
+    // topics and data of the events emitted since the last commit, in order of emission.
+    events: Vec<(Vec<u8>, Vec<u8>)>,
+    // staged writes and events at the begin of each open inner transaction, innermost last.
+    savepoints: Vec<Savepoint>,
+    #[cfg(debug_assertions)]
+    host_calls: HostCalls,
+}
+
+/// Staged writes and number of staged events at the begin of an inner transaction, restored if it is
+/// dropped without committing.
+#[derive(Debug, Default)]
+struct Savepoint {
+    entries: BTreeMap<Slot, Vec<u8>>,
+    events: usize,
+}
+
+thread_local! {
+    static CACHE: RefCell<StateCache> = RefCell::new(StateCache::default());
+}
+
+/// State host calls of the current call, counted in debug builds.
+#[cfg(debug_assertions)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HostCalls {
+    /// Reads sent to the host.
+    pub reads: u32,
+    /// Writes sent to the host.
+    pub writes: u32,
+    /// Reads served from the cache.
+    pub cached_reads: u32,
+    /// Writes replacing a staged write of the slot, or writing the value the slot already has.
+    pub coalesced_writes: u32,
+}
+
+/// Returns the state host calls made since the outermost transaction began.
+#[cfg(debug_assertions)]
+pub fn host_calls() -> HostCalls {
+    CACHE.with(|cache| cache.borrow().host_calls)
+}
+
+/// Caches the state read and written and the events emitted until it is committed, every `#[public]` function runs in one.
+/// Dropping the transaction without committing discards the staged writes and events.
+/// A transaction begun while another one is open is a savepoint of it: committing keeps its writes and
+/// events staged in the outer transaction, dropping it discards only the writes and events staged since it began.
+#[must_use = "dropping the transaction discards the staged writes"]
+#[derive(Debug)]
+pub struct Transaction {
+    outermost: bool,
+    committed: bool,
+}
+
+impl Transaction {
+    /// Begins a transaction, the following writes and events are staged until it is committed.
+    pub fn begin() -> Self {
+        let depth = CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            cache.depth += 1;
+            #[cfg(debug_assertions)]
+            if cache.depth == 1 {
+                cache.host_calls = HostCalls::default();
+            }
+            if cache.depth > 1 {
+                let savepoint = Savepoint {
+                    entries: cache
+                        .entries
+                        .iter()
+                        .filter(|(_, entry)| entry.dirty)
+                        .map(|(slot, entry)| (slot.clone(), entry.value.clone()))
+                        .collect(),
+                    events: cache.events.len(),
+                };
+                cache.savepoints.push(savepoint);
+            }
+            cache.depth
+        });
+        Transaction {
+            outermost: depth == 1,
+            committed: false,
+        }
+    }
+
+    /// Flushes the staged writes and events to the host and ends the transaction.
+    /// An inner transaction leaves them staged in the outer one.
+    pub fn commit(mut self) {
+        self.committed = true;
+        if self.outermost {
+            commit();
+        }
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if self.outermost {
+                // the host state may change before the next call.
+                cache.entries.clear();
+                cache.events.clear();
+            } else if let Some(savepoint) = cache.savepoints.pop() {
+                if !self.committed {
+                    // slots first written since the savepoint are read from the host again.
+                    cache
+                        .entries
+                        .retain(|slot, entry| !entry.dirty || savepoint.entries.contains_key(slot));
+                    for (slot, value) in savepoint.entries {
+                        cache.entries.insert(slot, Entry { value, dirty: true });
+                    }
+                    cache.events.truncate(savepoint.events);
+                }
+            }
+            cache.depth -= 1;
+        });
+    }
+}
+
+/// Returns true if a transaction is open and state writes are staged.
+pub fn in_transaction() -> bool {
+    CACHE.with(|cache| cache.borrow().depth > 0)
+}
+
+/// Flushes the writes and events staged so far to the host, a later failure of the call does not discard them.
+/// Does nothing outside of a transaction.
+pub fn commit() {
+    let (dirty, events) = CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let (dirty, clean): (BTreeMap<_, _>, _) = std::mem::take(&mut cache.entries)
+            .into_iter()
+            .partition(|(_, entry)| entry.dirty);
+        cache.entries = clean;
+        // the flushed writes and events are not discarded by an inner transaction either.
+        for savepoint in &mut cache.savepoints {
+            *savepoint = Savepoint::default();
+        }
+        #[cfg(debug_assertions)]
+        {
+            cache.host_calls.writes += dirty.len() as u32;
+        }
+        (dirty, std::mem::take(&mut cache.events))
+    });
+    for (slot, entry) in dirty {
+        slot.host_write(&entry.value);
+    }
+    for (topics, data) in events {
+        events::host_emit_event(&topics, &data);
+    }
+}
+
+/// Discards the writes and events staged since the last commit.
+pub fn rollback() {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.entries.retain(|_, entry| !entry.dirty);
+        cache.events.clear();
+        for savepoint in &mut cache.savepoints {
+            *savepoint = Savepoint::default();
+        }
+    });
+}
+
+/// Emits the event with the topics and data, staged if a transaction is open.
+pub(crate) fn emit_event(topics: Vec<u8>, data: Vec<u8>) {
+    let direct = CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.depth == 0 {
+            return Some((topics, data));
+        }
+        cache.events.push((topics, data));
+        None
+    });
+    if let Some((topics, data)) = direct {
+        events::host_emit_event(&topics, &data);
+    }
+}
+
+/// Returns the value of `slot`, from the cache if it was read or written in the transaction.
+fn read(slot: Slot) -> Vec<u8> {
+    let (cached, in_transaction) = CACHE.with(|cache| {
+        let cache = cache.borrow();
+        let cached = cache.entries.get(&slot).map(|entry| entry.value.clone());
+        (cached, cache.depth > 0)
+    });
+    #[cfg(debug_assertions)]
+    CACHE.with(|cache| {
+        let host_calls = &mut cache.borrow_mut().host_calls;
+        if cached.is_some() {
+            host_calls.cached_reads += 1;
+        } else {
+            host_calls.reads += 1;
+        }
+    });
+    if let Some(value) = cached {
+        return value;
+    }
+
+    let value = slot.host_read();
+    if in_transaction {
+        CACHE.with(|cache| {
+            let entry = Entry {
+                value: value.clone(),
+                dirty: false,
+            };
+            cache.borrow_mut().entries.insert(slot, entry);
+        });
+    }
+    value
+}
+
+/// Writes `value` to `slot`, staged in the cache if a transaction is open.
+fn write(slot: Slot, value: Vec<u8>) {
+    let direct = CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let cache = &mut *cache;
+        if cache.depth == 0 {
+            #[cfg(debug_assertions)]
+            {
+                cache.host_calls.writes += 1;
+            }
+            return Some((slot, value));
+        }
+
+        match cache.entries.get_mut(&slot) {
+            Some(entry) => {
+                #[cfg(debug_assertions)]
+                if entry.dirty || entry.value == value {
+                    cache.host_calls.coalesced_writes += 1;
+                }
+                // writing the value read from the host leaves the slot clean.
+                entry.dirty = entry.dirty || entry.value != value;
+                entry.value = value;
+            }
+            None => {
+                let entry = Entry { value, dirty: true };
+                cache.entries.insert(slot, entry);
+            }
+        }
+        None
+    });
+    if let Some((slot, value)) = direct {
+        slot.host_write(&value);
     }
 }
 
+/// Stores `value` in the static storage `slot`, staged if a transaction is open.
+pub(crate) fn write_static(slot: u32, value: Vec<u8>) {
+    write(Slot::Static(slot), value);
+}
+
+/// Returns the bytes stored in the static storage `slot`, cached if a transaction is open.
+pub(crate) fn read_static(slot: u32) -> Vec<u8> {
+    read(Slot::Static(slot))
+}
+
+/// Stores `value` at `key` of the mapping `id`, staged if a transaction is open.
+pub(crate) fn write_dynamic(id: u32, key: Vec<u8>, value: Vec<u8>) {
+    write(Slot::Dynamic(id, key), value);
+}
+
+/// Returns the bytes stored at `key` of the mapping `id`, cached if a transaction is open.
+pub(crate) fn read_dynamic(id: u32, key: Vec<u8>) -> Vec<u8> {
+    read(Slot::Dynamic(id, key))
+}
+
+/// Stores a u256 value in the state at `key`.
+pub fn store_u256(key: u32, value: U256) {
+    write_static(key, value.to_be_bytes_vec());
+}
+
 /// Returns u256 from state at `key`. If the key is not found, or the value is not a u256, returns an error.
 pub fn get_u256(key: u32) -> U256 {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        U256::from_be_slice(data.try_into().unwrap_or_default())
-    }
+    U256::from_be_slice(&read_static(key))
 }
 
 /// Stores a u64 value in the state at `key`.
 pub fn store_u64(key: u32, value: u64) {
-    let value_bytes = value.to_be_bytes().to_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value.to_be_bytes().to_vec());
 }
 
 /// Returns u64 from state at `key`. If the key is not found, or the value is not a u64, returns 0.
 pub fn get_u64(key: u32) -> u64 {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        u64::from_be_bytes(data.try_into().unwrap_or_default())
-    }
+    let data = read_static(key);
+    u64::from_be_bytes(data.as_slice().try_into().unwrap_or_default())
 }
 
 /// Stores a u32 value in the state at `key`.
 pub fn store_u32(key: u32, value: u32) {
-    let value_bytes = value.to_be_bytes().to_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value.to_be_bytes().to_vec());
 }
 
 /// Returns u32 from state at `key`. If the key is not found, or the value is not a u32, returns 0.
 pub fn get_u32(key: u32) -> u32 {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        u32::from_be_bytes(data.try_into().unwrap_or_default())
-    }
+    let data = read_static(key);
+    u32::from_be_bytes(data.as_slice().try_into().unwrap_or_default())
 }
 
 /// Stores a u16 value in the state at `key`.
 pub fn store_bytes32(key: u32, value: FixedBytes<32>) {
-    let value_bytes = value.abi_encode();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value.abi_encode());
 }
 
 /// Returns u16 from state at `key`. If the key is not found, or the value is not a u16, returns 0.
 pub fn get_bytes32(key: u32) -> FixedBytes<32> {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        FixedBytes::from_slice(data)
-    }
+    FixedBytes::from_slice(&read_static(key))
 }
 
 /// Stores a [u8] in the state at `key`.
 pub fn store_vec(key: u32, vector: &[u8]) {
-    let vector = vector.to_vec();
-    let ptr = vector.as_ptr() as u32;
-    let len = vector.len() as u32;
-    std::mem::forget(vector);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, vector.to_vec());
 }
 
 /// Returns [u8] from state at `key`.
 pub fn get_vec(key: u32) -> Vec<u8> {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        data.to_vec()
-    }
+    read_static(key)
 }
 
 /// Stores a bool value in the state at `key`. If the value is 0, stores 0u32, otherwise stores 1u32.
@@ -133,218 +488,222 @@ pub fn store_bool(key: u32, value: u32) {
     } else {
         1_u32.to_be_bytes().to_vec()
     };
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value_bytes);
 }
 
 /// Returns bool from state at `key`. If the value is 0u32, returns 0, otherwise returns 1.
 pub fn get_bool(key: u32) -> u32 {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        if u32::from_be_bytes(data.try_into().unwrap_or_default()) == 0 {
-            0
-        } else {
-            1
-        }
+    let data = read_static(key);
+    if u32::from_be_bytes(data.as_slice().try_into().unwrap_or_default()) == 0 {
+        0
+    } else {
+        1
     }
 }
 
 /// Stores FixedBytes<32> in the state at U256 `key` associated with mapping id `id`.
 pub fn store_mapping_u256_bytes32(id: u32, key: U256, value: FixedBytes<32>) {
-    // get ptr and len of key. forget the key.
-    let key = key.to_be_bytes_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    // get ptr and len of value. forget the value.
-    let value_bytes = value.abi_encode();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-
-    unsafe { store_dynamic_bytes(id, ptr_key, len_key, ptr, len) };
+    write_dynamic(id, key.to_be_bytes_vec(), value.abi_encode());
 }
 
 /// Returns FixedBytes<32> from state at U256 `key` associated with mapping id `id`. If the key is not found, returns an empty FixedBytes<32>.
 pub fn get_mapping_u256_bytes32(id: u32, key: U256) -> FixedBytes<32> {
-    // get ptr and len of key. forget the key.
-    let key = key.to_be_bytes_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    unsafe {
-        let ptr_packed = get_dynamic_bytes(id, ptr_key, len_key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        match FixedBytes::try_from(data) {
-            Ok(fixed_bytes) => fixed_bytes,
-            Err(_) => FixedBytes::default(),
-        }
-    }
+    let data = read_dynamic(id, key.to_be_bytes_vec());
+    FixedBytes::try_from(data.as_slice()).unwrap_or_default()
 }
 
 /// Stores FixedBytes<32> in the state at u64 `key` associated with mapping id `id`.
 pub fn store_mapping_u64_bytes32(id: u32, key: u64, value: FixedBytes<32>) {
-    let key = key.to_be_bytes().to_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-    // get ptr and len of value. forget the value.
-    let value_bytes = value.abi_encode();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-
-    unsafe { store_dynamic_bytes(id, ptr_key, len_key, ptr, len) };
+    write_dynamic(id, key.to_be_bytes().to_vec(), value.abi_encode());
 }
 
 /// Returns FixedBytes<32> from state at u64 `key` associated with mapping id `id`. If the key is not found, returns an empty FixedBytes<32>.
 pub fn get_mapping_u64_bytes32(id: u32, key: u64) -> FixedBytes<32> {
-    let key = key.to_be_bytes().to_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-    unsafe {
-        let ptr_packed = get_dynamic_bytes(id, ptr_key, len_key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        match FixedBytes::try_from(data) {
-            Ok(fixed_bytes) => fixed_bytes,
-            Err(_) => FixedBytes::default(),
-        }
-    }
+    let data = read_dynamic(id, key.to_be_bytes().to_vec());
+    FixedBytes::try_from(data.as_slice()).unwrap_or_default()
 }
 
 /// Stores FixedBytes<32> in the state at u32 `key` associated with mapping id `id`.
 pub fn store_mapping_u32_bytes32(id: u32, key: u32, value: FixedBytes<32>) {
-    let key = key.to_be_bytes().to_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-    let value_bytes = value.abi_encode();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe { store_dynamic_bytes(id, ptr_key, len_key, ptr, len) };
+    write_dynamic(id, key.to_be_bytes().to_vec(), value.abi_encode());
 }
 
 /// Returns FixedBytes<32> from state at u32 `key` associated with mapping id `id`. If the key is not found, returns an empty FixedBytes<32>.
 pub fn get_mapping_u32_bytes32(id: u32, key: u32) -> FixedBytes<32> {
-    let key = key.to_be_bytes().to_vec();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    unsafe {
-        let ptr_packed = get_dynamic_bytes(id, ptr_key, len_key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        match FixedBytes::try_from(data) {
-            Ok(fixed_bytes) => fixed_bytes,
-            Err(_) => FixedBytes::default(),
-        }
-    }
+    let data = read_dynamic(id, key.to_be_bytes().to_vec());
+    FixedBytes::try_from(data.as_slice()).unwrap_or_default()
 }
 
 /// Stores FixedBytes<32> in the state at FixedBytes<32> `key` associated with mapping id `id`.
 pub fn store_mapping_bytes32_bytes32(id: u32, key: FixedBytes<32>, value: FixedBytes<32>) {
-    let key = key.abi_encode();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    let value_bytes = value.abi_encode();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe { store_dynamic_bytes(id, ptr_key, len_key, ptr, len) };
+    write_dynamic(id, key.abi_encode(), value.abi_encode());
 }
 
 /// Returns FixedBytes<32> from state at FixedBytes<32> `key` associated with mapping id `id`. If the key is not found, returns an empty FixedBytes<32>.
 pub fn get_mapping_bytes32_bytes32(id: u32, key: FixedBytes<32>) -> FixedBytes<32> {
-    let key = key.abi_encode();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    unsafe {
-        let ptr_packed = get_dynamic_bytes(id, ptr_key, len_key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        match FixedBytes::try_from(data) {
-            Ok(fixed_bytes) => fixed_bytes,
-            Err(_) => FixedBytes::default(),
-        }
-    }
+    let data = read_dynamic(id, key.abi_encode());
+    FixedBytes::try_from(data.as_slice()).unwrap_or_default()
 }
 
 /// Stores u32 in the state at FixedBytes<32> `key` associated with mapping id `id`.
 pub fn store_mapping_bytes32_u32(id: u32, key: FixedBytes<32>, value: u32) {
-    let key = key.abi_encode();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    let value_bytes = value.to_be_bytes().to_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe { store_dynamic_bytes(id, ptr_key, len_key, ptr, len) };
+    write_dynamic(id, key.abi_encode(), value.to_be_bytes().to_vec());
 }
 
 /// Returns u32 from state at FixedBytes<32> `key` associated with mapping id `id`. If the key is not found, returns 0.
 pub fn get_mapping_bytes32_u32(id: u32, key: FixedBytes<32>) -> u32 {
-    let key = key.abi_encode();
-    let ptr_key = key.as_ptr() as u32;
-    let len_key = key.len() as u32;
-    std::mem::forget(key);
-
-    unsafe {
-        let ptr_packed = get_dynamic_bytes(id, ptr_key, len_key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        u32::from_be_bytes(data.try_into().unwrap_or_default())
-    }
+    let data = read_dynamic(id, key.abi_encode());
+    u32::from_be_bytes(data.as_slice().try_into().unwrap_or_default())
 }
 
 /// Stores `Address` in the state at `key`.
 pub fn store_address(key: u32, value: &types::Address) {
-    let value_bytes = value.as_bytes().to_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value.as_bytes().to_vec());
 }
 
 /// Returns `Address` from state at `key`. If the key is not found, or the value is not an `Address`, returns an empty `Address`.
 pub fn get_address(key: u32) -> types::Address {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        types::Address::new(data.try_into().unwrap_or([0; 33]))
-    }
+    let data = read_static(key);
+    types::Address::new(data.as_slice().try_into().unwrap_or([0; 33]))
 }
 
 /// Stores `ID` in the state at `key`.
 pub fn store_id(key: u32, value: &types::ID) {
-    let value_bytes = value.as_bytes().to_vec();
-    let ptr = value_bytes.as_ptr() as u32;
-    let len = value_bytes.len() as u32;
-    std::mem::forget(value_bytes);
-    unsafe {
-        store_bytes(key, ptr, len);
-    }
+    write_static(key, value.as_bytes().to_vec());
 }
 
 /// Returns `ID` from state at `key`. If the key is not found, or the value is not an `ID`, returns an empty `ID`.
 pub fn get_id(key: u32) -> types::ID {
-    unsafe {
-        let ptr_packed = get_bytes(key);
-        let data = slice::from_raw_parts((ptr_packed >> 32) as *mut u8, (ptr_packed as u16).into());
-        types::ID::new(data.try_into().unwrap_or([0; 32]))
+    let data = read_static(key);
+    types::ID::new(data.as_slice().try_into().unwrap_or([0; 32]))
+}
+
+// Fallible getters. The host returns no bytes for a slot that was never written, these return
+// `Ok(None)` for it, and an error if the stored bytes are not a valid encoding of the type.
+
+/// Decodes host bytes into a fixed size array. Returns `Ok(None)` if there are no bytes.
+fn decode_fixed<const N: usize>(data: Vec<u8>) -> Result<Option<[u8; N]>, StateError> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+    match data.as_slice().try_into() {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(_) => Err(StateError::InvalidLength {
+            expected: N,
+            found: data.len(),
+        }),
+    }
+}
+
+/// Returns u256 from state at `key`, or `None` if the key is not found.
+pub fn try_get_u256(key: u32) -> Result<Option<U256>, StateError> {
+    Ok(decode_fixed::<32>(read_static(key))?.map(U256::from_be_bytes))
+}
+
+/// Returns u64 from state at `key`, or `None` if the key is not found.
+pub fn try_get_u64(key: u32) -> Result<Option<u64>, StateError> {
+    Ok(decode_fixed(read_static(key))?.map(u64::from_be_bytes))
+}
+
+/// Returns u32 from state at `key`, or `None` if the key is not found.
+pub fn try_get_u32(key: u32) -> Result<Option<u32>, StateError> {
+    Ok(decode_fixed(read_static(key))?.map(u32::from_be_bytes))
+}
+
+/// Returns FixedBytes<32> from state at `key`, or `None` if the key is not found.
+pub fn try_get_bytes32(key: u32) -> Result<Option<FixedBytes<32>>, StateError> {
+    Ok(decode_fixed(read_static(key))?.map(FixedBytes::new))
+}
+
+/// Returns [u8] from state at `key`, or `None` if the key is not found or holds no bytes.
+pub fn try_get_vec(key: u32) -> Result<Option<Vec<u8>>, StateError> {
+    let data = read_static(key);
+    Ok((!data.is_empty()).then_some(data))
+}
+
+/// Returns bool from state at `key`, or `None` if the key is not found.
+/// Values other than 0u32 and 1u32 are an `InvalidEncoding` error.
+pub fn try_get_bool(key: u32) -> Result<Option<bool>, StateError> {
+    match decode_fixed(read_static(key))?.map(u32::from_be_bytes) {
+        None => Ok(None),
+        Some(0) => Ok(Some(false)),
+        Some(1) => Ok(Some(true)),
+        Some(_) => Err(StateError::InvalidEncoding),
+    }
+}
+
+/// Returns `Address` from state at `key`, or `None` if the key is not found.
+pub fn try_get_address(key: u32) -> Result<Option<types::Address>, StateError> {
+    Ok(decode_fixed(read_static(key))?.map(types::Address::new))
+}
+
+/// Returns `ID` from state at `key`, or `None` if the key is not found.
+pub fn try_get_id(key: u32) -> Result<Option<types::ID>, StateError> {
+    Ok(decode_fixed(read_static(key))?.map(types::ID::new))
+}
+
+/// Returns FixedBytes<32> from state at U256 `key` associated with mapping id `id`, or `None` if the key is not found.
+pub fn try_get_mapping_u256_bytes32(
+    id: u32,
+    key: U256,
+) -> Result<Option<FixedBytes<32>>, StateError> {
+    Ok(decode_fixed(read_dynamic(id, key.to_be_bytes_vec()))?.map(FixedBytes::new))
+}
+
+/// Returns FixedBytes<32> from state at u64 `key` associated with mapping id `id`, or `None` if the key is not found.
+pub fn try_get_mapping_u64_bytes32(
+    id: u32,
+    key: u64,
+) -> Result<Option<FixedBytes<32>>, StateError> {
+    Ok(decode_fixed(read_dynamic(id, key.to_be_bytes().to_vec()))?.map(FixedBytes::new))
+}
+
+/// Returns FixedBytes<32> from state at u32 `key` associated with mapping id `id`, or `None` if the key is not found.
+pub fn try_get_mapping_u32_bytes32(
+    id: u32,
+    key: u32,
+) -> Result<Option<FixedBytes<32>>, StateError> {
+    Ok(decode_fixed(read_dynamic(id, key.to_be_bytes().to_vec()))?.map(FixedBytes::new))
+}
+
+/// Returns FixedBytes<32> from state at FixedBytes<32> `key` associated with mapping id `id`, or `None` if the key is not found.
+pub fn try_get_mapping_bytes32_bytes32(
+    id: u32,
+    key: FixedBytes<32>,
+) -> Result<Option<FixedBytes<32>>, StateError> {
+    Ok(decode_fixed(read_dynamic(id, key.abi_encode()))?.map(FixedBytes::new))
+}
+
+/// Returns u32 from state at FixedBytes<32> `key` associated with mapping id `id`, or `None` if the key is not found.
+pub fn try_get_mapping_bytes32_u32(
+    id: u32,
+    key: FixedBytes<32>,
+) -> Result<Option<u32>, StateError> {
+    Ok(decode_fixed(read_dynamic(id, key.abi_encode()))?.map(u32::from_be_bytes))
+}
+
+#[cfg(test)]
+mod test_state {
+    use super::unpack_ptr;
+
+    #[test]
+    fn test_unpack_ptr_small() {
+        let (ptr, size) = unpack_ptr(0x0000_1000_0000_0020);
+        assert_eq!(ptr as usize, 0x1000);
+        assert_eq!(size, 0x20);
+    }
+
+    #[test]
+    fn test_unpack_ptr_over_64_kib() {
+        let (ptr, size) = unpack_ptr(0x0010_0000_0001_0001);
+        assert_eq!(ptr as usize, 0x0010_0000);
+        assert_eq!(size, 65_537);
+    }
+
+    #[test]
+    fn test_unpack_ptr_max() {
+        let (ptr, size) = unpack_ptr(u64::MAX);
+        assert_eq!(ptr as usize, u32::MAX as usize);
+        assert_eq!(size, u32::MAX as usize);
     }
 }
@@ -0,0 +1,227 @@
+use crate::state::{self, StateError};
+use crate::types;
+use crate::{FixedBytes, SolType, SolValue, U256};
+use core::marker::PhantomData;
+
+/// Static slots from `RESERVED_SLOT_START` are reserved for the sdk, `#[storage]` does not assign them.
+pub const RESERVED_SLOT_START: u32 = 0xFFFF_0000;
+/// Mapping ids from `RESERVED_ID_START` are reserved for the sdk, `#[storage]` does not assign them.
+pub const RESERVED_ID_START: u32 = 0xFFFF_0000;
+
+/// Encoding of a value, or a mapping key, in contract state.
+///
+/// Encodings of the primitive types match the `state::store_*` functions, so a slot written with
+/// `state::store_u64` can be read with a `StorageValue<u64>` and vice versa.
+pub trait StorageCodec: Sized {
+    /// Returns the bytes stored in state for the value.
+    fn encode(&self) -> Vec<u8>;
+    /// Decodes the value from the bytes stored in state.
+    fn decode(bytes: &[u8]) -> Result<Self, StateError>;
+}
+
+/// Returns `bytes` as a fixed size array, or an `InvalidLength` error.
+fn fixed<const N: usize>(bytes: &[u8]) -> Result<[u8; N], StateError> {
+    bytes.try_into().map_err(|_| StateError::InvalidLength {
+        expected: N,
+        found: bytes.len(),
+    })
+}
+
+impl StorageCodec for u32 {
+    fn encode(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(u32::from_be_bytes(fixed(bytes)?))
+    }
+}
+
+impl StorageCodec for u64 {
+    fn encode(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(u64::from_be_bytes(fixed(bytes)?))
+    }
+}
+
+impl StorageCodec for U256 {
+    fn encode(&self) -> Vec<u8> {
+        self.to_be_bytes_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(U256::from_be_bytes::<32>(fixed(bytes)?))
+    }
+}
+
+/// Stored as 0u32 or 1u32, same as `state::store_bool`.
+impl StorageCodec for bool {
+    fn encode(&self) -> Vec<u8> {
+        (*self as u32).encode()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        match u32::decode(bytes)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(StateError::InvalidEncoding),
+        }
+    }
+}
+
+impl<const N: usize> StorageCodec for FixedBytes<N> {
+    fn encode(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(FixedBytes::new(fixed(bytes)?))
+    }
+}
+
+impl StorageCodec for types::Address {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(types::Address::new(fixed(bytes)?))
+    }
+}
+
+impl StorageCodec for types::ID {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(types::ID::new(fixed(bytes)?))
+    }
+}
+
+/// Stored as is, same as `state::store_vec`.
+impl StorageCodec for Vec<u8> {
+    fn encode(&self) -> Vec<u8> {
+        self.clone()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Stores any `SolValue` (e.g. a struct declared with `sol!`) ABI encoded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Abi<T>(pub T);
+
+impl<T> StorageCodec for Abi<T>
+where
+    T: SolValue + From<<T::SolType as SolType>::RustType>,
+{
+    fn encode(&self) -> Vec<u8> {
+        self.0.abi_encode()
+    }
+    fn decode(bytes: &[u8]) -> Result<Self, StateError> {
+        T::abi_decode(bytes, true)
+            .map(Abi)
+            .map_err(|_| StateError::InvalidEncoding)
+    }
+}
+
+/// Decodes host bytes, where no bytes means nothing is stored.
+fn decode_stored<T: StorageCodec>(bytes: Vec<u8>) -> Result<Option<T>, StateError> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    T::decode(&bytes).map(Some)
+}
+
+/// Typed handle to a value stored in the static storage slot `slot`.
+#[derive(Debug)]
+pub struct StorageValue<T> {
+    slot: u32,
+    _value: PhantomData<T>,
+}
+
+impl<T: StorageCodec> StorageValue<T> {
+    /// Constructor function for StorageValue
+    pub const fn new(slot: u32) -> Self {
+        Self {
+            slot,
+            _value: PhantomData,
+        }
+    }
+
+    /// returns the static storage slot of the value
+    pub const fn slot(&self) -> u32 {
+        self.slot
+    }
+
+    /// Stores `value` in the slot.
+    pub fn set(&self, value: T) {
+        state::write_static(self.slot, value.encode());
+    }
+
+    /// Returns the value in the slot, `None` if nothing is stored, or an error if the stored bytes can not be decoded.
+    pub fn try_get(&self) -> Result<Option<T>, StateError> {
+        decode_stored(state::read_static(self.slot))
+    }
+
+    /// Returns the value in the slot. If nothing is stored, or the stored bytes can not be decoded, returns the default value.
+    pub fn get(&self) -> T
+    where
+        T: Default,
+    {
+        self.try_get().ok().flatten().unwrap_or_default()
+    }
+
+    /// Clears the slot, following reads return `None`.
+    pub fn clear(&self) {
+        state::write_static(self.slot, Vec::new());
+    }
+}
+
+/// Typed handle to the mapping with id `id`.
+#[derive(Debug)]
+pub struct StorageMap<K, V> {
+    id: u32,
+    _entry: PhantomData<(K, V)>,
+}
+
+impl<K: StorageCodec, V: StorageCodec> StorageMap<K, V> {
+    /// Constructor function for StorageMap
+    pub const fn new(id: u32) -> Self {
+        Self {
+            id,
+            _entry: PhantomData,
+        }
+    }
+
+    /// returns the id of the mapping
+    pub const fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Stores `value` at `key`.
+    pub fn insert(&self, key: K, value: V) {
+        state::write_dynamic(self.id, key.encode(), value.encode());
+    }
+
+    /// Returns the value at `key`, `None` if nothing is stored, or an error if the stored bytes can not be decoded.
+    pub fn try_get(&self, key: K) -> Result<Option<V>, StateError> {
+        decode_stored(state::read_dynamic(self.id, key.encode()))
+    }
+
+    /// Returns the value at `key`. If nothing is stored, or the stored bytes can not be decoded, returns the default value.
+    pub fn get(&self, key: K) -> V
+    where
+        V: Default,
+    {
+        self.try_get(key).ok().flatten().unwrap_or_default()
+    }
+
+    /// Removes the value at `key`, following reads return `None`.
+    pub fn remove(&self, key: K) {
+        state::write_dynamic(self.id, key.encode(), Vec::new());
+    }
+
+    /// Returns true if a value is stored at `key`.
+    pub fn contains(&self, key: K) -> bool {
+        !state::read_dynamic(self.id, key.encode()).is_empty()
+    }
+}
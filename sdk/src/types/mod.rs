@@ -14,20 +14,21 @@ impl Address {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+    /// Returns the address for the bytes, or `None` if they are not `LEN` bytes long.
+    #[must_use]
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Self)
+    }
 }
 
 impl PartialEq for Address {
     fn eq(&self, other: &Self) -> bool {
         self.0[..] == other.0[..]
     }
-    fn ne(&self, other: &Self) -> bool {
-        self.0[..] != other.0[..]
-    }
 }
 
 impl Default for Address {
     /// Returns a new Address with all bytes set to 0. Equivalent to Empty Address in hypersdk.
-    #[must_use]
     fn default() -> Self {
         Self([0u8; Self::LEN])
     }
@@ -54,14 +55,10 @@ impl PartialEq for ID {
     fn eq(&self, other: &Self) -> bool {
         self.0[..] == other.0[..]
     }
-    fn ne(&self, other: &Self) -> bool {
-        self.0[..] != other.0[..]
-    }
 }
 
 impl Default for ID {
     /// Returns a new ID with all bytes set to 0. Equivalent to Empty ID in hypersdk.
-    #[must_use]
     fn default() -> Self {
         Self([0u8; Self::LEN])
     }
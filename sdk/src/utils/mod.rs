@@ -1,4 +1,76 @@
-use crate::{slice, sol, types};
+#[cfg(not(feature = "mock-host"))]
+use crate::slice;
+use crate::{sol, types, SolInterface, SolValue};
+
+// Extern linked to wasm module as `env`. The function is implemented in the go runtime for wasm.
+#[cfg(not(feature = "mock-host"))]
+#[link(wasm_import_module = "env")]
+extern "C" {
+    /// Sets the data returned to the caller of the current call.
+    /// Takes a pointer to the bytes and the size of the bytes. The host copies the bytes.
+    #[link_name = "setReturnData"]
+    pub fn set_return_data_inner(ptr: u32, size: u32);
+
+    /// Sets the error data returned to the caller when the current call fails.
+    /// Takes a pointer to the bytes and the size of the bytes. The host copies the bytes.
+    #[link_name = "setRevertData"]
+    pub fn set_revert_data_inner(ptr: u32, size: u32);
+}
+
+/// Sets the data returned to the caller of the current call, replacing any data set before.
+/// `#[public]` functions with a return type set the ABI encoded return value with this function.
+pub fn set_return_data(data: &[u8]) {
+    #[cfg(not(feature = "mock-host"))]
+    unsafe {
+        set_return_data_inner(data.as_ptr() as u32, data.len() as u32)
+    };
+    #[cfg(feature = "mock-host")]
+    crate::mock::set_return_data(data);
+}
+
+/// ABI encodes the value and sets it as the data returned to the caller of the current call.
+pub fn set_return_value<T: SolValue>(value: &T) {
+    set_return_data(&value.abi_encode());
+}
+
+/// An error a contract call fails with.
+/// Implemented for the error enums `sol!` generates for the errors of an interface or contract.
+pub trait ContractError {
+    /// Returns the error selector followed by the ABI encoded error data.
+    fn revert_data(&self) -> Vec<u8>;
+}
+
+impl<T: SolInterface> ContractError for T {
+    fn revert_data(&self) -> Vec<u8> {
+        self.abi_encode()
+    }
+}
+
+/// Sets the error as the revert data of the current call, replacing any data set before.
+/// Returns false, so a public function can `return revert(error)`.
+/// `#[public]` functions returning `Err` revert with the error.
+pub fn revert<E: ContractError>(error: E) -> bool {
+    let data = error.revert_data();
+    #[cfg(not(feature = "mock-host"))]
+    unsafe {
+        set_revert_data_inner(data.as_ptr() as u32, data.len() as u32)
+    };
+    #[cfg(feature = "mock-host")]
+    crate::mock::set_revert_data(&data);
+    false
+}
+
+sol! {
+    /// Errors of the calls to `#[public]` functions.
+    #[derive(Debug, PartialEq, Eq)]
+    interface ICall {
+        /// The calldata can not be ABI decoded into the parameters of the function.
+        error InvalidCalldata();
+    }
+}
+
+pub use ICall::ICallErrors as CallError;
+pub use ICall::InvalidCalldata;
 
 /// Contains the Context of the Transaction. This is passed to the contract as a pointer from go runtime.
 #[derive(Debug, Copy, Clone)]
@@ -12,21 +84,36 @@ pub struct TxContext {
 
 impl TxContext {
     /// Unpacks the TxContext from the pointer.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn unpack(ptr: *const TxContext) -> Self {
         unsafe { *ptr }
     }
 
     /// Returns the msg_sender(caller) of the transaction.
+    #[cfg(not(feature = "mock-host"))]
     pub fn msg_sender(&self) -> types::Address {
         let msg_sender_bytes =
             unsafe { slice::from_raw_parts(self.msg_sender_ptr as *mut u8, types::Address::LEN) };
         types::Address::new(msg_sender_bytes.try_into().unwrap())
     }
 
+    /// Returns the msg_sender(caller) of the transaction.
+    /// With the mock host, `msg_sender_ptr` is a handle registered by `TxContextBuilder`.
+    #[cfg(feature = "mock-host")]
+    pub fn msg_sender(&self) -> types::Address {
+        crate::mock::msg_sender(self.msg_sender_ptr)
+    }
+
     /// Returns the time_stamp of the block, the transaction is included in.
     pub fn time_stamp(&self) -> i64 {
         self.time_stamp
     }
+
+    /// Returns a builder for a TxContext usable with the mock host.
+    #[cfg(feature = "mock-host")]
+    pub fn builder() -> crate::mock::TxContextBuilder {
+        crate::mock::TxContextBuilder::default()
+    }
 }
 
 sol!(
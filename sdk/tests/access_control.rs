@@ -0,0 +1,192 @@
+use seq_wasm_sdk::access_control::{
+    self, AccessControlError, InvalidAccount, MissingRole, NoPendingTransfer, RoleGranted,
+    RoleRevoked, RoleTransferStarted, ADMIN, GUARDIAN,
+};
+use seq_wasm_sdk::types::Address;
+use seq_wasm_sdk::{fixed_bytes, mock, SolEvent};
+
+/// `keccak256("OPERATOR_ROLE")`, a role of the contract.
+const OPERATOR: access_control::Role =
+    fixed_bytes!("97667070c54ef182b0f5858b034beac1b6f3089aa2d3188bb1e8929f4fa9b929");
+
+fn account(byte: u8) -> Address {
+    Address::new([byte; Address::LEN])
+}
+
+fn missing_role(role: access_control::Role, account: &Address) -> AccessControlError {
+    AccessControlError::MissingRole(MissingRole {
+        role,
+        account: account.as_bytes().to_vec().into(),
+    })
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    mock::reset();
+    let admin = account(1);
+    let operator = account(2);
+    access_control::grant_role_unchecked(ADMIN, &admin);
+    assert!(access_control::has_role(ADMIN, &admin));
+    assert!(!access_control::has_role(OPERATOR, &operator));
+
+    // only an admin can grant roles.
+    assert_eq!(
+        access_control::grant_role(&operator, OPERATOR, &operator),
+        Err(missing_role(ADMIN, &operator))
+    );
+    assert_eq!(
+        access_control::grant_role(&admin, OPERATOR, &operator),
+        Ok(())
+    );
+    assert!(access_control::has_role(OPERATOR, &operator));
+    assert_eq!(access_control::only_role(OPERATOR, &operator), Ok(()));
+    assert_eq!(
+        access_control::only_role(GUARDIAN, &operator),
+        Err(missing_role(GUARDIAN, &operator))
+    );
+
+    assert_eq!(
+        access_control::revoke_role(&admin, OPERATOR, &operator),
+        Ok(())
+    );
+    assert!(!access_control::has_role(OPERATOR, &operator));
+
+    let events = mock::take_events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        RoleGranted::decode_log_data(&events[1], true).unwrap(),
+        RoleGranted {
+            role: OPERATOR,
+            account: operator.as_bytes().to_vec().into(),
+        }
+    );
+    assert_eq!(
+        RoleRevoked::decode_log_data(&events[2], true).unwrap(),
+        RoleRevoked {
+            role: OPERATOR,
+            account: operator.as_bytes().to_vec().into(),
+        }
+    );
+}
+
+#[test]
+fn test_grant_role_twice_emits_once() {
+    mock::reset();
+    access_control::grant_role_unchecked(GUARDIAN, &account(1));
+    access_control::grant_role_unchecked(GUARDIAN, &account(1));
+    access_control::revoke_role_unchecked(OPERATOR, &account(1));
+    assert_eq!(mock::take_events().len(), 1);
+}
+
+#[test]
+fn test_renounce_role() {
+    mock::reset();
+    let guardian = account(1);
+    assert_eq!(
+        access_control::renounce_role(&guardian, GUARDIAN),
+        Err(missing_role(GUARDIAN, &guardian))
+    );
+    access_control::grant_role_unchecked(GUARDIAN, &guardian);
+    assert_eq!(access_control::renounce_role(&guardian, GUARDIAN), Ok(()));
+    assert!(!access_control::has_role(GUARDIAN, &guardian));
+}
+
+#[test]
+fn test_transfer_role() {
+    mock::reset();
+    let guardian = account(1);
+    let new_guardian = account(2);
+    access_control::grant_role_unchecked(GUARDIAN, &guardian);
+
+    // only a member can start a transfer of the role.
+    assert_eq!(
+        access_control::transfer_role(&new_guardian, GUARDIAN, &new_guardian),
+        Err(missing_role(GUARDIAN, &new_guardian))
+    );
+    assert_eq!(
+        access_control::accept_role(&new_guardian, GUARDIAN),
+        Err(AccessControlError::NoPendingTransfer(NoPendingTransfer {
+            role: GUARDIAN,
+            account: new_guardian.as_bytes().to_vec().into(),
+        }))
+    );
+
+    mock::take_events();
+    assert_eq!(
+        access_control::transfer_role(&guardian, GUARDIAN, &new_guardian),
+        Ok(())
+    );
+    // the role is not transferred before it is accepted.
+    assert!(access_control::has_role(GUARDIAN, &guardian));
+    assert!(!access_control::has_role(GUARDIAN, &new_guardian));
+    assert_eq!(
+        RoleTransferStarted::decode_log_data(&mock::take_events()[0], true).unwrap(),
+        RoleTransferStarted {
+            role: GUARDIAN,
+            from: guardian.as_bytes().to_vec().into(),
+            to: new_guardian.as_bytes().to_vec().into(),
+        }
+    );
+
+    assert_eq!(access_control::accept_role(&new_guardian, GUARDIAN), Ok(()));
+    assert!(!access_control::has_role(GUARDIAN, &guardian));
+    assert!(access_control::has_role(GUARDIAN, &new_guardian));
+    // a transfer can be accepted once.
+    assert!(access_control::accept_role(&new_guardian, GUARDIAN).is_err());
+}
+
+#[test]
+fn test_accept_role_after_revoke() {
+    mock::reset();
+    let guardian = account(1);
+    let new_guardian = account(2);
+    access_control::grant_role_unchecked(GUARDIAN, &guardian);
+    assert_eq!(
+        access_control::transfer_role(&guardian, GUARDIAN, &new_guardian),
+        Ok(())
+    );
+    access_control::revoke_role_unchecked(GUARDIAN, &guardian);
+    assert_eq!(
+        access_control::accept_role(&new_guardian, GUARDIAN),
+        Err(missing_role(GUARDIAN, &guardian))
+    );
+    assert!(!access_control::has_role(GUARDIAN, &new_guardian));
+}
+
+#[test]
+fn test_role_members() {
+    mock::reset();
+    assert!(access_control::role_members(OPERATOR).is_empty());
+    for byte in 1..=3 {
+        access_control::grant_role_unchecked(OPERATOR, &account(byte));
+    }
+    access_control::grant_role_unchecked(GUARDIAN, &account(4));
+    access_control::revoke_role_unchecked(OPERATOR, &account(2));
+    assert_eq!(
+        access_control::role_members(OPERATOR),
+        vec![account(1), account(3)]
+    );
+    assert_eq!(access_control::role_members(GUARDIAN), vec![account(4)]);
+
+    // the new account of a transfer replaces the old one.
+    assert_eq!(
+        access_control::transfer_role(&account(4), GUARDIAN, &account(5)),
+        Ok(())
+    );
+    assert_eq!(access_control::accept_role(&account(5), GUARDIAN), Ok(()));
+    assert_eq!(access_control::role_members(GUARDIAN), vec![account(5)]);
+}
+
+#[test]
+fn test_parse_account() {
+    assert_eq!(
+        access_control::parse_account(account(3).as_bytes()),
+        Ok(account(3))
+    );
+    assert_eq!(
+        access_control::parse_account(&[1, 2, 3]),
+        Err(AccessControlError::InvalidAccount(InvalidAccount {
+            account: vec![1, 2, 3].into(),
+        }))
+    );
+}
@@ -0,0 +1,80 @@
+use seq_wasm_sdk::allocator::{allocate, deallocate, HostBuffer};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting the bytes allocated and not yet deallocated by each thread.
+struct CountingAllocator;
+
+thread_local! {
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.with(|live| live.set(live.get() + layout.size() as isize));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.with(|live| live.set(live.get() - layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+/// Returns the bytes allocated and not yet deallocated by the current thread.
+fn live_bytes() -> isize {
+    LIVE_BYTES.with(Cell::get)
+}
+
+/// Allocates a buffer like the host does and writes `bytes` to it.
+fn host_buffer(bytes: &[u8]) -> HostBuffer {
+    let ptr = allocate(bytes.len());
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+        HostBuffer::from_raw_parts(ptr, bytes.len())
+    }
+}
+
+#[test]
+fn test_allocate_does_not_alias() {
+    let first = allocate(64);
+    let second = allocate(64);
+    assert_ne!(first, second);
+    unsafe {
+        first.write_bytes(1, 64);
+        second.write_bytes(2, 64);
+        assert_eq!(*first.add(63), 1);
+        deallocate(first, 64);
+        deallocate(second, 64);
+    }
+}
+
+#[test]
+fn test_host_buffer() {
+    let bytes: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+    let buffer = host_buffer(&bytes);
+    assert_eq!(&*buffer, bytes.as_slice());
+    assert_eq!(buffer.into_vec(), bytes);
+
+    // dropped buffers are deallocated.
+    let live = live_bytes();
+    for _ in 0..1_000 {
+        drop(host_buffer(&bytes));
+    }
+    assert_eq!(live_bytes(), live);
+
+    // a buffer that is not dropped stays allocated.
+    std::mem::forget(host_buffer(&bytes));
+    assert_eq!(live_bytes(), live + bytes.len() as isize);
+}
+
+#[test]
+fn test_empty_host_buffer() {
+    let buffer = unsafe { HostBuffer::from_raw_parts(std::ptr::null_mut(), 0) };
+    assert!(buffer.is_empty());
+    assert!(buffer.into_vec().is_empty());
+    assert!(host_buffer(&[]).into_vec().is_empty());
+}
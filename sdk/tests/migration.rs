@@ -0,0 +1,42 @@
+use seq_wasm_sdk::migration::{self, LayoutMigrated, LayoutVersionMismatch, MigrationError};
+use seq_wasm_sdk::storage::{StorageValue, RESERVED_SLOT_START};
+use seq_wasm_sdk::{mock, SolEvent};
+
+#[test]
+fn test_layout_version_defaults_to_0() {
+    mock::reset();
+    assert_eq!(migration::layout_version(), 0);
+    assert_eq!(migration::only_layout_version(0), Ok(()));
+    assert_eq!(
+        migration::only_layout_version(1),
+        Err(MigrationError::LayoutVersionMismatch(
+            LayoutVersionMismatch {
+                expected: 1,
+                found: 0,
+            }
+        ))
+    );
+}
+
+#[test]
+fn test_layout_version_in_reserved_slot() {
+    mock::reset();
+    migration::set_layout_version(3);
+    assert_eq!(migration::layout_version(), 3);
+    assert_eq!(StorageValue::<u32>::new(RESERVED_SLOT_START).get(), 3);
+}
+
+#[test]
+fn test_complete_migration() {
+    mock::reset();
+    migration::set_layout_version(1);
+    migration::complete_migration(1);
+    assert_eq!(migration::layout_version(), 2);
+    assert_eq!(
+        LayoutMigrated::decode_log_data(&mock::take_events()[0], true).unwrap(),
+        LayoutMigrated {
+            fromVersion: 1,
+            toVersion: 2,
+        }
+    );
+}
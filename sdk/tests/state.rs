@@ -0,0 +1,263 @@
+use seq_wasm_sdk::events::Event;
+use seq_wasm_sdk::state::StateError;
+use seq_wasm_sdk::types::{Address, ID};
+use seq_wasm_sdk::utils::{self, TxContext};
+use seq_wasm_sdk::{
+    mock, precompiles, sol, state, FixedBytes, SolError, SolEvent, SolValue, B256, U256,
+};
+
+#[test]
+fn test_static_round_trip() {
+    mock::reset();
+    state::store_u256(1, U256::from(123));
+    state::store_u64(2, 123);
+    state::store_u32(3, 123);
+    state::store_bool(4, 1);
+    state::store_bytes32(5, FixedBytes::from([1; 32]));
+    state::store_vec(6, &[1, 2, 3]);
+    state::store_address(7, &Address::new([7; Address::LEN]));
+    state::store_id(8, &ID::new([8; ID::LEN]));
+
+    assert_eq!(state::get_u256(1), U256::from(123));
+    assert_eq!(state::get_u64(2), 123);
+    assert_eq!(state::get_u32(3), 123);
+    assert_eq!(state::get_bool(4), 1);
+    assert_eq!(state::get_bytes32(5), FixedBytes::from([1; 32]));
+    assert_eq!(state::get_vec(6), vec![1, 2, 3]);
+    assert!(state::get_address(7) == Address::new([7; Address::LEN]));
+    assert!(state::get_id(8) == ID::new([8; ID::LEN]));
+}
+
+#[test]
+fn test_vec_over_64_kib() {
+    mock::reset();
+    let vkey: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+    state::store_vec(1, &vkey);
+    assert_eq!(state::get_vec(1), vkey);
+}
+
+#[test]
+fn test_mapping_round_trip() {
+    mock::reset();
+    state::store_mapping_u256_bytes32(1, U256::from(569), FixedBytes::from([1; 32]));
+    state::store_mapping_u64_bytes32(2, 4230, FixedBytes::from([2; 32]));
+    state::store_mapping_u32_bytes32(3, 123, FixedBytes::from([3; 32]));
+    state::store_mapping_bytes32_bytes32(4, FixedBytes::from([2; 32]), FixedBytes::from([4; 32]));
+    state::store_mapping_bytes32_u32(5, FixedBytes::from([2; 32]), 12369);
+
+    assert_eq!(
+        state::get_mapping_u256_bytes32(1, U256::from(569)),
+        FixedBytes::from([1; 32])
+    );
+    assert_eq!(
+        state::get_mapping_u64_bytes32(2, 4230),
+        FixedBytes::from([2; 32])
+    );
+    assert_eq!(
+        state::get_mapping_u32_bytes32(3, 123),
+        FixedBytes::from([3; 32])
+    );
+    assert_eq!(
+        state::get_mapping_bytes32_bytes32(4, FixedBytes::from([2; 32])),
+        FixedBytes::from([4; 32])
+    );
+    assert_eq!(
+        state::get_mapping_bytes32_u32(5, FixedBytes::from([2; 32])),
+        12369
+    );
+    // same key under a different mapping id is not found.
+    assert_eq!(
+        state::get_mapping_u32_bytes32(4, 123),
+        FixedBytes::default()
+    );
+}
+
+#[test]
+fn test_missing_values_default() {
+    mock::reset();
+    assert_eq!(state::get_u64(1), 0);
+    assert_eq!(state::get_u32(1), 0);
+    assert_eq!(state::get_bool(1), 0);
+    assert!(state::get_vec(1).is_empty());
+    assert!(state::get_address(1) == Address::default());
+}
+
+#[test]
+fn test_reset_clears_state() {
+    state::store_u64(1, 123);
+    mock::reset();
+    assert_eq!(state::get_u64(1), 0);
+}
+
+#[test]
+fn test_gnark_verify_result() {
+    mock::reset();
+    assert!(!precompiles::gnark_verify(vec![], vec![], vec![], vec![]));
+    mock::set_verify_result(true);
+    assert!(precompiles::gnark_verify(vec![], vec![], vec![], vec![]));
+    assert_eq!(mock::verify_calls(), 2);
+}
+
+#[test]
+fn test_balances() {
+    mock::reset();
+    let address = Address::new([1; Address::LEN]);
+    precompiles::set_balance(address.clone(), ID::default(), 100);
+    assert_eq!(precompiles::get_balance(address, ID::default()), 100);
+    assert_eq!(
+        precompiles::get_balance(Address::default(), ID::default()),
+        0
+    );
+}
+
+#[test]
+fn test_tx_context_builder() {
+    let sender = Address::new([3; Address::LEN]);
+    let tx_ctx = TxContext::builder()
+        .msg_sender(sender.clone())
+        .time_stamp(149)
+        .build();
+    assert!(tx_ctx.msg_sender() == sender);
+    assert_eq!(tx_ctx.time_stamp(), 149);
+}
+
+#[test]
+fn test_try_get_absent() {
+    mock::reset();
+    assert_eq!(state::try_get_u256(1), Ok(None));
+    assert_eq!(state::try_get_u64(1), Ok(None));
+    assert_eq!(state::try_get_u32(1), Ok(None));
+    assert_eq!(state::try_get_bool(1), Ok(None));
+    assert_eq!(state::try_get_bytes32(1), Ok(None));
+    assert_eq!(state::try_get_vec(1), Ok(None));
+    assert!(state::try_get_address(1).unwrap().is_none());
+    assert!(state::try_get_id(1).unwrap().is_none());
+    assert_eq!(state::try_get_mapping_u64_bytes32(1, 1), Ok(None));
+    assert_eq!(
+        state::try_get_mapping_bytes32_u32(1, FixedBytes::ZERO),
+        Ok(None)
+    );
+}
+
+#[test]
+fn test_try_get_written_zero() {
+    mock::reset();
+    state::store_u64(1, 0);
+    state::store_bool(2, 0);
+    state::store_mapping_u64_bytes32(1, 1, FixedBytes::ZERO);
+    assert_eq!(state::try_get_u64(1), Ok(Some(0)));
+    assert_eq!(state::try_get_bool(2), Ok(Some(false)));
+    assert_eq!(
+        state::try_get_mapping_u64_bytes32(1, 1),
+        Ok(Some(FixedBytes::ZERO))
+    );
+}
+
+#[test]
+fn test_try_get_values() {
+    mock::reset();
+    state::store_u256(1, U256::from(123));
+    state::store_u32(2, 123);
+    state::store_bool(3, 1);
+    state::store_address(4, &Address::new([4; Address::LEN]));
+    state::store_mapping_u256_bytes32(1, U256::from(1), FixedBytes::from([1; 32]));
+    state::store_mapping_bytes32_u32(2, FixedBytes::from([2; 32]), 12369);
+    assert_eq!(state::try_get_u256(1), Ok(Some(U256::from(123))));
+    assert_eq!(state::try_get_u32(2), Ok(Some(123)));
+    assert_eq!(state::try_get_bool(3), Ok(Some(true)));
+    assert!(state::try_get_address(4).unwrap() == Some(Address::new([4; Address::LEN])));
+    assert_eq!(
+        state::try_get_mapping_u256_bytes32(1, U256::from(1)),
+        Ok(Some(FixedBytes::from([1; 32])))
+    );
+    assert_eq!(
+        state::try_get_mapping_bytes32_u32(2, FixedBytes::from([2; 32])),
+        Ok(Some(12369))
+    );
+}
+
+#[test]
+fn test_try_get_invalid_length() {
+    mock::reset();
+    state::store_vec(1, &[1, 2, 3]);
+    assert_eq!(
+        state::try_get_u64(1),
+        Err(StateError::InvalidLength {
+            expected: 8,
+            found: 3
+        })
+    );
+    assert_eq!(
+        state::try_get_bytes32(1),
+        Err(StateError::InvalidLength {
+            expected: 32,
+            found: 3
+        })
+    );
+    assert!(state::try_get_address(1).is_err());
+    // the infallible getter can not tell the difference.
+    assert_eq!(state::get_u64(1), 0);
+}
+
+#[test]
+fn test_try_get_bool_invalid_encoding() {
+    mock::reset();
+    state::store_u32(1, 2);
+    assert_eq!(state::try_get_bool(1), Err(StateError::InvalidEncoding));
+}
+
+#[test]
+fn test_set_return_data() {
+    mock::reset();
+    assert!(mock::take_return_data().is_empty());
+
+    // the last call replaces the data set before.
+    utils::set_return_data(&[1, 2, 3]);
+    utils::set_return_value(&(U256::from(123), true));
+    assert_eq!(
+        mock::take_return_data(),
+        (U256::from(123), true).abi_encode()
+    );
+    assert!(mock::take_return_data().is_empty());
+}
+
+sol! {
+    interface ITest {
+        error Failed(uint64 code);
+    }
+}
+
+#[test]
+fn test_revert() {
+    mock::reset();
+    assert!(!utils::revert(ITest::ITestErrors::Failed(ITest::Failed {
+        code: 7
+    })));
+    assert_eq!(
+        mock::take_revert_data(),
+        ITest::Failed { code: 7 }.abi_encode()
+    );
+    assert!(mock::take_revert_data().is_empty());
+}
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    event Stored(uint64 indexed slot, bytes32 value);
+}
+
+#[test]
+fn test_emit_event() {
+    mock::reset();
+    let event = Stored {
+        slot: 7,
+        value: FixedBytes::from([1; 32]),
+    };
+    event.emit();
+
+    let events = mock::take_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].topics()[0], Stored::SIGNATURE_HASH);
+    assert_eq!(events[0].topics()[1], B256::from(U256::from(7)));
+    assert_eq!(Stored::decode_log_data(&events[0], true).unwrap(), event);
+    assert!(mock::take_events().is_empty());
+}
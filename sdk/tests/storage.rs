@@ -0,0 +1,130 @@
+use seq_wasm_sdk::state::StateError;
+use seq_wasm_sdk::storage::{Abi, StorageMap, StorageValue};
+use seq_wasm_sdk::types::{Address, ID};
+use seq_wasm_sdk::{mock, sol, state, FixedBytes, U256};
+
+sol!(
+    #[derive(Default, PartialEq, Debug)]
+    struct Checkpoint {
+        uint64 height;
+        bytes32 header;
+    }
+);
+
+const LATEST_BLOCK: StorageValue<u64> = StorageValue::new(1);
+const FROZEN: StorageValue<bool> = StorageValue::new(2);
+const CHECKPOINT: StorageValue<Abi<Checkpoint>> = StorageValue::new(3);
+const HEADERS: StorageMap<u64, FixedBytes<32>> = StorageMap::new(1);
+const START_BLOCKS: StorageMap<FixedBytes<32>, u32> = StorageMap::new(2);
+const CHECKPOINTS: StorageMap<U256, Abi<Checkpoint>> = StorageMap::new(3);
+
+#[test]
+fn test_storage_value_round_trip() {
+    mock::reset();
+    assert_eq!(LATEST_BLOCK.try_get(), Ok(None));
+    assert_eq!(LATEST_BLOCK.get(), 0);
+    LATEST_BLOCK.set(123);
+    FROZEN.set(true);
+    assert_eq!(LATEST_BLOCK.get(), 123);
+    assert!(FROZEN.get());
+
+    let guardian: StorageValue<Address> = StorageValue::new(4);
+    guardian.set(Address::new([4; Address::LEN]));
+    assert!(guardian.get() == Address::new([4; Address::LEN]));
+
+    let asset: StorageValue<ID> = StorageValue::new(5);
+    asset.set(ID::new([5; ID::LEN]));
+    assert!(asset.get() == ID::new([5; ID::LEN]));
+
+    let vkey: StorageValue<Vec<u8>> = StorageValue::new(6);
+    vkey.set(vec![1, 2, 3]);
+    assert_eq!(vkey.get(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_storage_value_matches_state_functions() {
+    mock::reset();
+    LATEST_BLOCK.set(123);
+    assert_eq!(state::get_u64(LATEST_BLOCK.slot()), 123);
+    state::store_bool(FROZEN.slot(), 1);
+    assert!(FROZEN.get());
+
+    let nonce: StorageValue<U256> = StorageValue::new(7);
+    state::store_u256(nonce.slot(), U256::from(9));
+    assert_eq!(nonce.get(), U256::from(9));
+
+    HEADERS.insert(10, FixedBytes::from([1; 32]));
+    assert_eq!(
+        state::get_mapping_u64_bytes32(HEADERS.id(), 10),
+        FixedBytes::from([1; 32])
+    );
+    state::store_mapping_bytes32_u32(START_BLOCKS.id(), FixedBytes::from([2; 32]), 5);
+    assert_eq!(START_BLOCKS.get(FixedBytes::from([2; 32])), 5);
+}
+
+#[test]
+fn test_storage_value_clear() {
+    mock::reset();
+    LATEST_BLOCK.set(123);
+    LATEST_BLOCK.clear();
+    assert_eq!(LATEST_BLOCK.try_get(), Ok(None));
+    assert_eq!(LATEST_BLOCK.get(), 0);
+}
+
+#[test]
+fn test_storage_map_round_trip() {
+    mock::reset();
+    assert!(!HEADERS.contains(10));
+    assert_eq!(HEADERS.try_get(10), Ok(None));
+    HEADERS.insert(10, FixedBytes::ZERO);
+    assert!(HEADERS.contains(10));
+    assert_eq!(HEADERS.try_get(10), Ok(Some(FixedBytes::ZERO)));
+    assert_eq!(HEADERS.try_get(11), Ok(None));
+}
+
+#[test]
+fn test_storage_map_remove() {
+    mock::reset();
+    HEADERS.insert(10, FixedBytes::from([1; 32]));
+    HEADERS.remove(10);
+    assert!(!HEADERS.contains(10));
+    assert_eq!(HEADERS.try_get(10), Ok(None));
+    // removing an absent key is a no-op.
+    HEADERS.remove(11);
+    assert!(!HEADERS.contains(11));
+}
+
+#[test]
+fn test_abi_values() {
+    mock::reset();
+    let checkpoint = Checkpoint {
+        height: 10,
+        header: FixedBytes::from([1; 32]),
+    };
+    CHECKPOINT.set(Abi(checkpoint.clone()));
+    assert_eq!(CHECKPOINT.get().0, checkpoint);
+
+    CHECKPOINTS.insert(U256::from(1), Abi(checkpoint.clone()));
+    assert_eq!(CHECKPOINTS.get(U256::from(1)).0, checkpoint);
+    assert_eq!(CHECKPOINTS.get(U256::from(2)).0, Checkpoint::default());
+}
+
+#[test]
+fn test_decode_errors() {
+    mock::reset();
+    state::store_vec(LATEST_BLOCK.slot(), &[1, 2, 3]);
+    assert_eq!(
+        LATEST_BLOCK.try_get(),
+        Err(StateError::InvalidLength {
+            expected: 8,
+            found: 3
+        })
+    );
+    assert_eq!(LATEST_BLOCK.get(), 0);
+
+    state::store_u32(FROZEN.slot(), 2);
+    assert_eq!(FROZEN.try_get(), Err(StateError::InvalidEncoding));
+
+    state::store_vec(CHECKPOINT.slot(), &[1, 2, 3]);
+    assert_eq!(CHECKPOINT.try_get(), Err(StateError::InvalidEncoding));
+}
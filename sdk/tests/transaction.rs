@@ -0,0 +1,227 @@
+use seq_wasm_sdk::events::Event;
+use seq_wasm_sdk::mock;
+use seq_wasm_sdk::state::{self, Transaction};
+use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+use seq_wasm_sdk::{sol, SolEvent};
+
+sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    event Stored(uint64 indexed slot);
+}
+
+/// Returns the slots of the `Stored` events sent to the host.
+fn stored_events() -> Vec<u64> {
+    mock::take_events()
+        .iter()
+        .map(|log| Stored::decode_log_data(log, true).unwrap().slot)
+        .collect()
+}
+
+#[test]
+fn test_writes_outside_transaction() {
+    mock::reset();
+    assert!(!state::in_transaction());
+    state::store_u64(1, 7);
+    assert_eq!(state::get_u64(1), 7);
+}
+
+#[test]
+fn test_commit() {
+    mock::reset();
+    let transaction = Transaction::begin();
+    assert!(state::in_transaction());
+    state::store_u64(1, 7);
+    StorageMap::<u32, u64>::new(2).insert(3, 9);
+    transaction.commit();
+
+    assert!(!state::in_transaction());
+    assert_eq!(state::get_u64(1), 7);
+    assert_eq!(StorageMap::<u32, u64>::new(2).get(3), 9);
+}
+
+#[test]
+fn test_drop_discards_writes() {
+    mock::reset();
+    state::store_u64(1, 7);
+    {
+        let _transaction = Transaction::begin();
+        state::store_u64(1, 8);
+        StorageMap::<u32, u64>::new(2).insert(3, 9);
+
+        // staged writes are visible to reads in the transaction.
+        assert_eq!(state::get_u64(1), 8);
+        assert_eq!(StorageMap::<u32, u64>::new(2).get(3), 9);
+    }
+
+    assert!(!state::in_transaction());
+    assert_eq!(state::get_u64(1), 7);
+    assert!(!StorageMap::<u32, u64>::new(2).contains(3));
+}
+
+#[test]
+fn test_explicit_commit() {
+    mock::reset();
+    {
+        let _transaction = Transaction::begin();
+        StorageValue::<u64>::new(1).set(7);
+        state::commit();
+        StorageValue::<u64>::new(2).set(8);
+    }
+
+    assert_eq!(StorageValue::<u64>::new(1).try_get(), Ok(Some(7)));
+    assert_eq!(StorageValue::<u64>::new(2).try_get(), Ok(None));
+}
+
+#[test]
+fn test_rollback() {
+    mock::reset();
+    let transaction = Transaction::begin();
+    state::store_u64(1, 7);
+    state::rollback();
+    state::store_u64(2, 8);
+    transaction.commit();
+
+    assert_eq!(state::get_u64(1), 0);
+    assert_eq!(state::get_u64(2), 8);
+}
+
+#[test]
+fn test_nested_transaction_commits_into_outer() {
+    mock::reset();
+    {
+        let _outer = Transaction::begin();
+        let inner = Transaction::begin();
+        state::store_u64(1, 7);
+        inner.commit();
+        assert!(state::in_transaction());
+        assert_eq!(state::get_u64(1), 7);
+    }
+
+    assert_eq!(state::get_u64(1), 0);
+}
+
+#[test]
+fn test_nested_transaction_discards_own_writes() {
+    mock::reset();
+    state::store_u64(3, 5);
+    let outer = Transaction::begin();
+    state::store_u64(1, 7);
+    Stored { slot: 1 }.emit();
+    assert_eq!(state::get_u64(3), 5);
+    {
+        let _inner = Transaction::begin();
+        state::store_u64(1, 8);
+        state::store_u64(2, 9);
+        state::store_u64(3, 6);
+        Stored { slot: 2 }.emit();
+        assert_eq!(state::get_u64(1), 8);
+    }
+
+    // the writes and events of the outer transaction are still staged.
+    assert!(state::in_transaction());
+    assert_eq!(state::get_u64(1), 7);
+    assert_eq!(state::get_u64(2), 0);
+    assert_eq!(state::get_u64(3), 5);
+    outer.commit();
+    assert_eq!(stored_events(), vec![1]);
+    assert_eq!(state::get_u64(1), 7);
+    assert_eq!(state::get_u64(2), 0);
+    assert_eq!(state::get_u64(3), 5);
+}
+
+#[test]
+fn test_nested_transaction_keeps_flushed_writes() {
+    mock::reset();
+    {
+        let _outer = Transaction::begin();
+        let _inner = Transaction::begin();
+        state::store_u64(1, 7);
+        state::commit();
+        state::store_u64(2, 8);
+    }
+
+    assert_eq!(state::get_u64(1), 7);
+    assert_eq!(state::get_u64(2), 0);
+}
+
+#[test]
+fn test_events_staged_until_commit() {
+    mock::reset();
+    Stored { slot: 1 }.emit();
+    assert_eq!(stored_events(), vec![1]);
+
+    let transaction = Transaction::begin();
+    Stored { slot: 2 }.emit();
+    Stored { slot: 3 }.emit();
+    assert!(stored_events().is_empty());
+    transaction.commit();
+    assert_eq!(stored_events(), vec![2, 3]);
+}
+
+#[test]
+fn test_events_discarded_with_writes() {
+    mock::reset();
+    {
+        let _transaction = Transaction::begin();
+        Stored { slot: 1 }.emit();
+        state::commit();
+        Stored { slot: 2 }.emit();
+        state::rollback();
+        Stored { slot: 3 }.emit();
+    }
+    assert_eq!(stored_events(), vec![1]);
+
+    // the next transaction does not send the discarded events.
+    Transaction::begin().commit();
+    assert!(stored_events().is_empty());
+}
+
+#[test]
+fn test_cached_reads_see_host_state() {
+    mock::reset();
+    state::store_u64(1, 7);
+    let transaction = Transaction::begin();
+    assert_eq!(state::get_u64(1), 7);
+    state::store_u64(1, 8);
+    assert_eq!(state::get_u64(1), 8);
+
+    // rolled back writes are read from the host again.
+    state::rollback();
+    assert_eq!(state::get_u64(1), 7);
+    transaction.commit();
+    assert_eq!(state::get_u64(1), 7);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_host_calls() {
+    mock::reset();
+    state::store_vec(1, &[1; 4096]);
+    let vkey = StorageMap::<u32, u64>::new(2);
+
+    let transaction = Transaction::begin();
+    assert_eq!(state::host_calls(), state::HostCalls::default());
+    for _ in 0..3 {
+        assert_eq!(state::get_vec(1), vec![1; 4096]);
+        assert!(!vkey.contains(3));
+    }
+    for i in 0..3 {
+        vkey.insert(3, i);
+        state::store_u64(4, i);
+    }
+    // writing the value read from the host does not make the slot dirty.
+    state::store_vec(1, &[1; 4096]);
+    assert_eq!(vkey.get(3), 2);
+    transaction.commit();
+
+    assert_eq!(
+        state::host_calls(),
+        state::HostCalls {
+            reads: 2,
+            writes: 2,
+            cached_reads: 5,
+            coalesced_writes: 5,
+        }
+    );
+    assert_eq!(state::get_u64(4), 2);
+}
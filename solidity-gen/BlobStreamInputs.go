@@ -36,53 +36,15 @@ type BinaryMerkleProof struct {
 	NumLeaves *big.Int
 }
 
-// CommitHeaderRangeInput is an auto generated low-level Go binding around an user-defined struct.
-type CommitHeaderRangeInput struct {
-	Proof        []byte
-	PublicValues []byte
-}
-
 // DataRootTuple is an auto generated low-level Go binding around an user-defined struct.
 type DataRootTuple struct {
 	Height   *big.Int
 	DataRoot [32]byte
 }
 
-// InitializerInput is an auto generated low-level Go binding around an user-defined struct.
-type InitializerInput struct {
-	Height                    uint64
-	Header                    [32]byte
-	BlobstreamProgramVKeyHash []byte
-	BlobstreamProgramVKey     []byte
-}
-
-// UpdateFreezeInput is an auto generated low-level Go binding around an user-defined struct.
-type UpdateFreezeInput struct {
-	Freeze bool
-}
-
-// UpdateGenesisStateInput is an auto generated low-level Go binding around an user-defined struct.
-type UpdateGenesisStateInput struct {
-	Height uint64
-	Header [32]byte
-}
-
-// UpdateProgramVkeyInput is an auto generated low-level Go binding around an user-defined struct.
-type UpdateProgramVkeyInput struct {
-	BlobstreamProgramVKeyHash []byte
-	BlobstreamProgramVKey     []byte
-}
-
-// VAInput is an auto generated low-level Go binding around an user-defined struct.
-type VAInput struct {
-	TupleRootNonce *big.Int
-	Tuple          DataRootTuple
-	Proof          BinaryMerkleProof
-}
-
 // BlobStreamInputsMetaData contains all meta data concerning the BlobStreamInputs contract.
 var BlobStreamInputsMetaData = &bind.MetaData{
-	ABI: "[{\"inputs\":[{\"components\":[{\"internalType\":\"bytes\",\"name\":\"proof\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"publicValues\",\"type\":\"bytes\"}],\"internalType\":\"structCommitHeaderRangeInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"commitHeaderRange\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"uint64\",\"name\":\"height\",\"type\":\"uint64\"},{\"internalType\":\"bytes32\",\"name\":\"header\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKeyHash\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKey\",\"type\":\"bytes\"}],\"internalType\":\"structInitializerInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"initializer\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"bool\",\"name\":\"freeze\",\"type\":\"bool\"}],\"internalType\":\"structUpdateFreezeInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"updateFreeze\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"uint64\",\"name\":\"height\",\"type\":\"uint64\"},{\"internalType\":\"bytes32\",\"name\":\"header\",\"type\":\"bytes32\"}],\"internalType\":\"structUpdateGenesisStateInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"updateGenesisState\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKeyHash\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKey\",\"type\":\"bytes\"}],\"internalType\":\"structUpdateProgramVkeyInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"updateProgramVkey\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"uint256\",\"name\":\"tuple_root_nonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"structDataRootTuple\",\"name\":\"tuple\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"internalType\":\"structBinaryMerkleProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"internalType\":\"structVAInput\",\"name\":\"inputs\",\"type\":\"tuple\"}],\"name\":\"verifyAppend\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"}]",
+	ABI: "[{\"inputs\":[],\"name\":\"AlreadyInitialized\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"ContractFrozen\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"EmptyBatch\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"EmptySharesProof\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"InvalidAccount\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidCalldata\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidLeafKeys\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"beginKey\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"endKey\",\"type\":\"uint256\"}],\"name\":\"InvalidLeafRange\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidMerkleProof\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidNamespaceMerkleProof\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidProof\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"}],\"name\":\"InvalidProofNonce\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"InvalidPublicValues\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"beginKey\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"endKey\",\"type\":\"uint256\"}],\"name\":\"InvalidShareRange\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"name\":\"KeyNotInTree\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint32\",\"name\":\"expected\",\"type\":\"uint32\"},{\"internalType\":\"uint32\",\"name\":\"found\",\"type\":\"uint32\"}],\"name\":\"LayoutVersionMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"expected\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"found\",\"type\":\"uint256\"}],\"name\":\"LeavesCountMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"MissingRole\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"NoPendingTransfer\",\"type\":\"error\"},{\"inputs\":[],\"name\":\"NotInitialized\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"relayer\",\"type\":\"bytes\"}],\"name\":\"RelayerNotApproved\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"expected\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"found\",\"type\":\"uint256\"}],\"name\":\"RowProofsCountMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"expected\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"found\",\"type\":\"uint256\"}],\"name\":\"ShareProofsCountMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"expected\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"found\",\"type\":\"uint256\"}],\"name\":\"SharesCountMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"latestBlock\",\"type\":\"uint64\"},{\"internalType\":\"uint64\",\"name\":\"targetBlock\",\"type\":\"uint64\"}],\"name\":\"TargetBlockNotInRange\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"targetBlock\",\"type\":\"uint64\"},{\"internalType\":\"uint64\",\"name\":\"maxBlock\",\"type\":\"uint64\"}],\"name\":\"TargetBlockTooFar\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"latestBlock\",\"type\":\"uint64\"},{\"internalType\":\"uint64\",\"name\":\"trustedBlock\",\"type\":\"uint64\"}],\"name\":\"TrustedBlockMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"expected\",\"type\":\"bytes32\"},{\"internalType\":\"bytes32\",\"name\":\"found\",\"type\":\"bytes32\"}],\"name\":\"TrustedHeaderMismatch\",\"type\":\"error\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"latestBlock\",\"type\":\"uint64\"}],\"name\":\"TrustedHeaderNotFound\",\"type\":\"error\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"bool\",\"name\":\"checkRelayer\",\"type\":\"bool\"}],\"name\":\"CheckRelayerUpdated\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"},{\"indexed\":true,\"internalType\":\"uint64\",\"name\":\"startBlock\",\"type\":\"uint64\"},{\"indexed\":true,\"internalType\":\"uint64\",\"name\":\"endBlock\",\"type\":\"uint64\"},{\"indexed\":true,\"internalType\":\"bytes32\",\"name\":\"dataCommitment\",\"type\":\"bytes32\"}],\"name\":\"DataCommitmentStored\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"bool\",\"name\":\"frozen\",\"type\":\"bool\"}],\"name\":\"FreezeUpdated\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"uint64\",\"name\":\"blockNumber\",\"type\":\"uint64\"},{\"indexed\":false,\"internalType\":\"bytes32\",\"name\":\"headerHash\",\"type\":\"bytes32\"}],\"name\":\"HeadUpdate\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"uint32\",\"name\":\"fromVersion\",\"type\":\"uint32\"},{\"indexed\":false,\"internalType\":\"uint32\",\"name\":\"toVersion\",\"type\":\"uint32\"}],\"name\":\"LayoutMigrated\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"programVkeyHash\",\"type\":\"bytes\"}],\"name\":\"ProgramVkeyUpdated\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"relayer\",\"type\":\"bytes\"},{\"indexed\":false,\"internalType\":\"bool\",\"name\":\"approved\",\"type\":\"bool\"}],\"name\":\"RelayerApprovalUpdated\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":true,\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"RoleGranted\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":true,\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"RoleRevoked\",\"type\":\"event\"},{\"anonymous\":false,\"inputs\":[{\"indexed\":true,\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"from\",\"type\":\"bytes\"},{\"indexed\":false,\"internalType\":\"bytes\",\"name\":\"to\",\"type\":\"bytes\"}],\"name\":\"RoleTransferStarted\",\"type\":\"event\"},{\"inputs\":[],\"name\":\"acceptGuardian\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"height\",\"type\":\"uint64\"}],\"name\":\"blockHeightToHeaderHash\",\"outputs\":[{\"internalType\":\"bytes32\",\"name\":\"\",\"type\":\"bytes32\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"proof\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"publicValues\",\"type\":\"bytes\"}],\"name\":\"commitHeaderRange\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"bytes\",\"name\":\"proof\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"publicValues\",\"type\":\"bytes\"}],\"internalType\":\"struct CommitHeaderRangeInput[]\",\"name\":\"inputs\",\"type\":\"tuple[]\"}],\"name\":\"commitHeaderRanges\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"}],\"name\":\"dataCommitment\",\"outputs\":[{\"internalType\":\"bytes32\",\"name\":\"\",\"type\":\"bytes32\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"frozen\",\"outputs\":[{\"internalType\":\"bool\",\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"grantRole\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"guardian\",\"outputs\":[{\"internalType\":\"bytes\",\"name\":\"\",\"type\":\"bytes\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"height\",\"type\":\"uint64\"},{\"internalType\":\"bytes32\",\"name\":\"header\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKeyHash\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKey\",\"type\":\"bytes\"}],\"name\":\"initializer\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"latestBlock\",\"outputs\":[{\"internalType\":\"uint64\",\"name\":\"\",\"type\":\"uint64\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"migrateRoles\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"programVkeyHash\",\"outputs\":[{\"internalType\":\"bytes\",\"name\":\"\",\"type\":\"bytes\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"}],\"name\":\"renounceRole\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"},{\"internalType\":\"bytes\",\"name\":\"account\",\"type\":\"bytes\"}],\"name\":\"revokeRole\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes32\",\"name\":\"role\",\"type\":\"bytes32\"}],\"name\":\"roleMembers\",\"outputs\":[{\"internalType\":\"bytes[]\",\"name\":\"\",\"type\":\"bytes[]\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bool\",\"name\":\"checkRelayer\",\"type\":\"bool\"}],\"name\":\"setCheckRelayer\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"relayer\",\"type\":\"bytes\"},{\"internalType\":\"bool\",\"name\":\"approved\",\"type\":\"bool\"}],\"name\":\"setRelayerApproval\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[],\"name\":\"stateProofNonce\",\"outputs\":[{\"internalType\":\"uint256\",\"name\":\"\",\"type\":\"uint256\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"newGuardian\",\"type\":\"bytes\"}],\"name\":\"transferGuardian\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bool\",\"name\":\"freeze\",\"type\":\"bool\"}],\"name\":\"updateFreeze\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint64\",\"name\":\"height\",\"type\":\"uint64\"},{\"internalType\":\"bytes32\",\"name\":\"header\",\"type\":\"bytes32\"}],\"name\":\"updateGenesisState\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKeyHash\",\"type\":\"bytes\"},{\"internalType\":\"bytes\",\"name\":\"blobstreamProgramVKey\",\"type\":\"bytes\"}],\"name\":\"updateProgramVkey\",\"outputs\":[],\"stateMutability\":\"nonpayable\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"struct DataRootTuple\",\"name\":\"tuple\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"internalType\":\"struct BinaryMerkleProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"name\":\"verifyAttestation\",\"outputs\":[{\"internalType\":\"bool\",\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"struct DataRootTuple[]\",\"name\":\"tuples\",\"type\":\"tuple[]\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256[]\",\"name\":\"keys\",\"type\":\"uint256[]\"}],\"internalType\":\"struct BinaryMerkleMultiproof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"name\":\"verifyAttestationMulti\",\"outputs\":[{\"internalType\":\"bool\",\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"struct DataRootTuple[]\",\"name\":\"tuples\",\"type\":\"tuple[]\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"beginKey\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"endKey\",\"type\":\"uint256\"}],\"internalType\":\"struct BinaryMerkleRangeProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"name\":\"verifyAttestationRange\",\"outputs\":[{\"internalType\":\"bool\",\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"uint256\",\"name\":\"proofNonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"struct DataRootTuple\",\"name\":\"tuple\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"internalType\":\"struct BinaryMerkleProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"internalType\":\"struct VAInput[]\",\"name\":\"inputs\",\"type\":\"tuple[]\"}],\"name\":\"verifyAttestations\",\"outputs\":[{\"internalType\":\"bytes\",\"name\":\"\",\"type\":\"bytes\"}],\"stateMutability\":\"view\",\"type\":\"function\"},{\"inputs\":[{\"components\":[{\"internalType\":\"bytes[]\",\"name\":\"data\",\"type\":\"bytes[]\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"beginKey\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"endKey\",\"type\":\"uint256\"},{\"components\":[{\"components\":[{\"internalType\":\"bytes1\",\"name\":\"version\",\"type\":\"bytes1\"},{\"internalType\":\"bytes28\",\"name\":\"id\",\"type\":\"bytes28\"}],\"internalType\":\"struct Namespace\",\"name\":\"min\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes1\",\"name\":\"version\",\"type\":\"bytes1\"},{\"internalType\":\"bytes28\",\"name\":\"id\",\"type\":\"bytes28\"}],\"internalType\":\"struct Namespace\",\"name\":\"max\",\"type\":\"tuple\"},{\"internalType\":\"bytes32\",\"name\":\"digest\",\"type\":\"bytes32\"}],\"internalType\":\"struct NamespaceNode[]\",\"name\":\"sideNodes\",\"type\":\"tuple[]\"}],\"internalType\":\"struct NamespaceMerkleMultiproof[]\",\"name\":\"shareProofs\",\"type\":\"tuple[]\"},{\"components\":[{\"internalType\":\"bytes1\",\"name\":\"version\",\"type\":\"bytes1\"},{\"internalType\":\"bytes28\",\"name\":\"id\",\"type\":\"bytes28\"}],\"internalType\":\"struct Namespace\",\"name\":\"namespace\",\"type\":\"tuple\"},{\"components\":[{\"components\":[{\"internalType\":\"bytes1\",\"name\":\"version\",\"type\":\"bytes1\"},{\"internalType\":\"bytes28\",\"name\":\"id\",\"type\":\"bytes28\"}],\"internalType\":\"struct Namespace\",\"name\":\"min\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes1\",\"name\":\"version\",\"type\":\"bytes1\"},{\"internalType\":\"bytes28\",\"name\":\"id\",\"type\":\"bytes28\"}],\"internalType\":\"struct Namespace\",\"name\":\"max\",\"type\":\"tuple\"},{\"internalType\":\"bytes32\",\"name\":\"digest\",\"type\":\"bytes32\"}],\"internalType\":\"struct NamespaceNode[]\",\"name\":\"rowRoots\",\"type\":\"tuple[]\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"internalType\":\"struct BinaryMerkleProof[]\",\"name\":\"rowProofs\",\"type\":\"tuple[]\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"tupleRootNonce\",\"type\":\"uint256\"},{\"components\":[{\"internalType\":\"uint256\",\"name\":\"height\",\"type\":\"uint256\"},{\"internalType\":\"bytes32\",\"name\":\"dataRoot\",\"type\":\"bytes32\"}],\"internalType\":\"struct DataRootTuple\",\"name\":\"tuple\",\"type\":\"tuple\"},{\"components\":[{\"internalType\":\"bytes32[]\",\"name\":\"sideNodes\",\"type\":\"bytes32[]\"},{\"internalType\":\"uint256\",\"name\":\"key\",\"type\":\"uint256\"},{\"internalType\":\"uint256\",\"name\":\"numLeaves\",\"type\":\"uint256\"}],\"internalType\":\"struct BinaryMerkleProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"internalType\":\"struct AttestationProof\",\"name\":\"attestationProof\",\"type\":\"tuple\"}],\"internalType\":\"struct SharesProof\",\"name\":\"proof\",\"type\":\"tuple\"}],\"name\":\"verifySharesToDataRootTuple\",\"outputs\":[{\"internalType\":\"bool\",\"name\":\"\",\"type\":\"bool\"}],\"stateMutability\":\"view\",\"type\":\"function\"}]",
 }
 
 // BlobStreamInputsABI is the input ABI used to generate the binding from.
@@ -231,128 +193,138 @@ func (_BlobStreamInputs *BlobStreamInputsTransactorRaw) Transact(opts *bind.Tran
 	return _BlobStreamInputs.Contract.contract.Transact(opts, method, params...)
 }
 
-// CommitHeaderRange is a paid mutator transaction binding the contract method 0x4710c5f5.
+// VerifyAttestation is a free data retrieval call binding the contract method 0x1f3302a9.
 //
-// Solidity: function commitHeaderRange((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) CommitHeaderRange(opts *bind.TransactOpts, inputs CommitHeaderRangeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "commitHeaderRange", inputs)
+// Solidity: function verifyAttestation(uint256 proofNonce, (uint256,bytes32) tuple, (bytes32[],uint256,uint256) proof) view returns(bool)
+func (_BlobStreamInputs *BlobStreamInputsCaller) VerifyAttestation(opts *bind.CallOpts, proofNonce *big.Int, tuple DataRootTuple, proof BinaryMerkleProof) (bool, error) {
+	var out []interface{}
+	err := _BlobStreamInputs.contract.Call(opts, &out, "verifyAttestation", proofNonce, tuple, proof)
+
+	if err != nil {
+		return *new(bool), err
+	}
+
+	out0 := *abi.ConvertType(out[0], new(bool)).(*bool)
+
+	return out0, err
+
 }
 
-// CommitHeaderRange is a paid mutator transaction binding the contract method 0x4710c5f5.
+// VerifyAttestation is a free data retrieval call binding the contract method 0x1f3302a9.
 //
-// Solidity: function commitHeaderRange((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) CommitHeaderRange(inputs CommitHeaderRangeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.CommitHeaderRange(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function verifyAttestation(uint256 proofNonce, (uint256,bytes32) tuple, (bytes32[],uint256,uint256) proof) view returns(bool)
+func (_BlobStreamInputs *BlobStreamInputsSession) VerifyAttestation(proofNonce *big.Int, tuple DataRootTuple, proof BinaryMerkleProof) (bool, error) {
+	return _BlobStreamInputs.Contract.VerifyAttestation(&_BlobStreamInputs.CallOpts, proofNonce, tuple, proof)
 }
 
-// CommitHeaderRange is a paid mutator transaction binding the contract method 0x4710c5f5.
+// VerifyAttestation is a free data retrieval call binding the contract method 0x1f3302a9.
 //
-// Solidity: function commitHeaderRange((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) CommitHeaderRange(inputs CommitHeaderRangeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.CommitHeaderRange(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function verifyAttestation(uint256 proofNonce, (uint256,bytes32) tuple, (bytes32[],uint256,uint256) proof) view returns(bool)
+func (_BlobStreamInputs *BlobStreamInputsCallerSession) VerifyAttestation(proofNonce *big.Int, tuple DataRootTuple, proof BinaryMerkleProof) (bool, error) {
+	return _BlobStreamInputs.Contract.VerifyAttestation(&_BlobStreamInputs.CallOpts, proofNonce, tuple, proof)
 }
 
-// Initializer is a paid mutator transaction binding the contract method 0xff231ada.
+// CommitHeaderRange is a paid mutator transaction binding the contract method 0x8455a3cf.
 //
-// Solidity: function initializer((uint64,bytes32,bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) Initializer(opts *bind.TransactOpts, inputs InitializerInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "initializer", inputs)
+// Solidity: function commitHeaderRange(bytes proof, bytes publicValues) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactor) CommitHeaderRange(opts *bind.TransactOpts, proof []byte, publicValues []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.contract.Transact(opts, "commitHeaderRange", proof, publicValues)
 }
 
-// Initializer is a paid mutator transaction binding the contract method 0xff231ada.
+// CommitHeaderRange is a paid mutator transaction binding the contract method 0x8455a3cf.
 //
-// Solidity: function initializer((uint64,bytes32,bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) Initializer(inputs InitializerInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.Initializer(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function commitHeaderRange(bytes proof, bytes publicValues) returns()
+func (_BlobStreamInputs *BlobStreamInputsSession) CommitHeaderRange(proof []byte, publicValues []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.CommitHeaderRange(&_BlobStreamInputs.TransactOpts, proof, publicValues)
 }
 
-// Initializer is a paid mutator transaction binding the contract method 0xff231ada.
+// CommitHeaderRange is a paid mutator transaction binding the contract method 0x8455a3cf.
 //
-// Solidity: function initializer((uint64,bytes32,bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) Initializer(inputs InitializerInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.Initializer(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function commitHeaderRange(bytes proof, bytes publicValues) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactorSession) CommitHeaderRange(proof []byte, publicValues []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.CommitHeaderRange(&_BlobStreamInputs.TransactOpts, proof, publicValues)
 }
 
-// UpdateFreeze is a paid mutator transaction binding the contract method 0x173bb932.
+// Initializer is a paid mutator transaction binding the contract method 0x586e885a.
 //
-// Solidity: function updateFreeze((bool) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateFreeze(opts *bind.TransactOpts, inputs UpdateFreezeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "updateFreeze", inputs)
+// Solidity: function initializer(uint64 height, bytes32 header, bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactor) Initializer(opts *bind.TransactOpts, height uint64, header [32]byte, blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.contract.Transact(opts, "initializer", height, header, blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
 
-// UpdateFreeze is a paid mutator transaction binding the contract method 0x173bb932.
+// Initializer is a paid mutator transaction binding the contract method 0x586e885a.
 //
-// Solidity: function updateFreeze((bool) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) UpdateFreeze(inputs UpdateFreezeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateFreeze(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function initializer(uint64 height, bytes32 header, bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsSession) Initializer(height uint64, header [32]byte, blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.Initializer(&_BlobStreamInputs.TransactOpts, height, header, blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
 
-// UpdateFreeze is a paid mutator transaction binding the contract method 0x173bb932.
+// Initializer is a paid mutator transaction binding the contract method 0x586e885a.
 //
-// Solidity: function updateFreeze((bool) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateFreeze(inputs UpdateFreezeInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateFreeze(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function initializer(uint64 height, bytes32 header, bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactorSession) Initializer(height uint64, header [32]byte, blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.Initializer(&_BlobStreamInputs.TransactOpts, height, header, blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
 
-// UpdateGenesisState is a paid mutator transaction binding the contract method 0x541466cf.
+// UpdateFreeze is a paid mutator transaction binding the contract method 0x82e0cf6b.
 //
-// Solidity: function updateGenesisState((uint64,bytes32) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateGenesisState(opts *bind.TransactOpts, inputs UpdateGenesisStateInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "updateGenesisState", inputs)
+// Solidity: function updateFreeze(bool freeze) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateFreeze(opts *bind.TransactOpts, freeze bool) (*types.Transaction, error) {
+	return _BlobStreamInputs.contract.Transact(opts, "updateFreeze", freeze)
 }
 
-// UpdateGenesisState is a paid mutator transaction binding the contract method 0x541466cf.
+// UpdateFreeze is a paid mutator transaction binding the contract method 0x82e0cf6b.
 //
-// Solidity: function updateGenesisState((uint64,bytes32) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) UpdateGenesisState(inputs UpdateGenesisStateInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateGenesisState(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateFreeze(bool freeze) returns()
+func (_BlobStreamInputs *BlobStreamInputsSession) UpdateFreeze(freeze bool) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateFreeze(&_BlobStreamInputs.TransactOpts, freeze)
 }
 
-// UpdateGenesisState is a paid mutator transaction binding the contract method 0x541466cf.
+// UpdateFreeze is a paid mutator transaction binding the contract method 0x82e0cf6b.
 //
-// Solidity: function updateGenesisState((uint64,bytes32) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateGenesisState(inputs UpdateGenesisStateInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateGenesisState(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateFreeze(bool freeze) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateFreeze(freeze bool) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateFreeze(&_BlobStreamInputs.TransactOpts, freeze)
 }
 
-// UpdateProgramVkey is a paid mutator transaction binding the contract method 0xf3ca5c8d.
+// UpdateGenesisState is a paid mutator transaction binding the contract method 0x03ba33fd.
 //
-// Solidity: function updateProgramVkey((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateProgramVkey(opts *bind.TransactOpts, inputs UpdateProgramVkeyInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "updateProgramVkey", inputs)
+// Solidity: function updateGenesisState(uint64 height, bytes32 header) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateGenesisState(opts *bind.TransactOpts, height uint64, header [32]byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.contract.Transact(opts, "updateGenesisState", height, header)
 }
 
-// UpdateProgramVkey is a paid mutator transaction binding the contract method 0xf3ca5c8d.
+// UpdateGenesisState is a paid mutator transaction binding the contract method 0x03ba33fd.
 //
-// Solidity: function updateProgramVkey((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) UpdateProgramVkey(inputs UpdateProgramVkeyInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateProgramVkey(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateGenesisState(uint64 height, bytes32 header) returns()
+func (_BlobStreamInputs *BlobStreamInputsSession) UpdateGenesisState(height uint64, header [32]byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateGenesisState(&_BlobStreamInputs.TransactOpts, height, header)
 }
 
-// UpdateProgramVkey is a paid mutator transaction binding the contract method 0xf3ca5c8d.
+// UpdateGenesisState is a paid mutator transaction binding the contract method 0x03ba33fd.
 //
-// Solidity: function updateProgramVkey((bytes,bytes) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateProgramVkey(inputs UpdateProgramVkeyInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.UpdateProgramVkey(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateGenesisState(uint64 height, bytes32 header) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateGenesisState(height uint64, header [32]byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateGenesisState(&_BlobStreamInputs.TransactOpts, height, header)
 }
 
-// VerifyAppend is a paid mutator transaction binding the contract method 0x2122bc38.
+// UpdateProgramVkey is a paid mutator transaction binding the contract method 0x6cc5524a.
 //
-// Solidity: function verifyAppend((uint256,(uint256,bytes32),(bytes32[],uint256,uint256)) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactor) VerifyAppend(opts *bind.TransactOpts, inputs VAInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.contract.Transact(opts, "verifyAppend", inputs)
+// Solidity: function updateProgramVkey(bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactor) UpdateProgramVkey(opts *bind.TransactOpts, blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.contract.Transact(opts, "updateProgramVkey", blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
 
-// VerifyAppend is a paid mutator transaction binding the contract method 0x2122bc38.
+// UpdateProgramVkey is a paid mutator transaction binding the contract method 0x6cc5524a.
 //
-// Solidity: function verifyAppend((uint256,(uint256,bytes32),(bytes32[],uint256,uint256)) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsSession) VerifyAppend(inputs VAInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.VerifyAppend(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateProgramVkey(bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsSession) UpdateProgramVkey(blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateProgramVkey(&_BlobStreamInputs.TransactOpts, blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
 
-// VerifyAppend is a paid mutator transaction binding the contract method 0x2122bc38.
+// UpdateProgramVkey is a paid mutator transaction binding the contract method 0x6cc5524a.
 //
-// Solidity: function verifyAppend((uint256,(uint256,bytes32),(bytes32[],uint256,uint256)) inputs) returns()
-func (_BlobStreamInputs *BlobStreamInputsTransactorSession) VerifyAppend(inputs VAInput) (*types.Transaction, error) {
-	return _BlobStreamInputs.Contract.VerifyAppend(&_BlobStreamInputs.TransactOpts, inputs)
+// Solidity: function updateProgramVkey(bytes blobstreamProgramVKeyHash, bytes blobstreamProgramVKey) returns()
+func (_BlobStreamInputs *BlobStreamInputsTransactorSession) UpdateProgramVkey(blobstreamProgramVKeyHash []byte, blobstreamProgramVKey []byte) (*types.Transaction, error) {
+	return _BlobStreamInputs.Contract.UpdateProgramVkey(&_BlobStreamInputs.TransactOpts, blobstreamProgramVKeyHash, blobstreamProgramVKey)
 }
@@ -2,48 +2,126 @@
 
 pragma solidity ^0.8.20;
 
-struct InitializerInput {
-    uint64  height;
-    bytes32 header;
-    bytes blobstreamProgramVKeyHash;
-    bytes blobstreamProgramVKey;
+struct DataRootTuple{
+        uint256 height;
+        bytes32 dataRoot;
 }
-struct UpdateFreezeInput{
-    bool freeze;
+struct CommitHeaderRangeInput{
+    bytes proof;
+    bytes publicValues;
 }
-struct UpdateGenesisStateInput{
-    uint64 height;
-    bytes32 header;
+struct BinaryMerkleProof{
+    bytes32[] sideNodes;
+    uint256 key;
+    uint256 numLeaves;
 }
-struct UpdateProgramVkeyInput{
-    bytes blobstreamProgramVKeyHash;
-    bytes blobstreamProgramVKey;
+struct BinaryMerkleRangeProof{
+    bytes32[] sideNodes;
+    uint256 beginKey;
+    uint256 endKey;
 }
-struct CommitHeaderRangeInput {
-    bytes proof;
-    bytes publicValues;
+struct BinaryMerkleMultiproof{
+    bytes32[] sideNodes;
+    uint256[] keys;
 }
-struct VAInput {
-    uint256 tuple_root_nonce;
+struct Namespace{
+    bytes1 version;
+    bytes28 id;
+}
+struct NamespaceNode{
+    Namespace min;
+    Namespace max;
+    bytes32 digest;
+}
+struct NamespaceMerkleMultiproof{
+    uint256 beginKey;
+    uint256 endKey;
+    NamespaceNode[] sideNodes;
+}
+struct AttestationProof{
+    uint256 tupleRootNonce;
     DataRootTuple tuple;
     BinaryMerkleProof proof;
 }
-
-struct DataRootTuple{
-        uint256 height;
-        bytes32 dataRoot;
+struct SharesProof{
+    bytes[] data;
+    NamespaceMerkleMultiproof[] shareProofs;
+    Namespace namespace;
+    NamespaceNode[] rowRoots;
+    BinaryMerkleProof[] rowProofs;
+    AttestationProof attestationProof;
 }
-struct BinaryMerkleProof{
-    bytes32[] sideNodes;
-    uint256 key;
-    uint256 numLeaves;
+struct VAInput{
+    uint256 proofNonce;
+    DataRootTuple tuple;
+    BinaryMerkleProof proof;
 }
 
 interface BlobStreamInputs {
-    function initializer(InitializerInput calldata inputs) external; 
-    function updateFreeze(UpdateFreezeInput calldata inputs) external; 
-    function updateGenesisState(UpdateGenesisStateInput calldata inputs) external;  
-    function updateProgramVkey(UpdateProgramVkeyInput calldata inputs) external;  
-    function commitHeaderRange(CommitHeaderRangeInput calldata inputs) external;  
-    function verifyAttestation(VAInput calldata inputs) external;
+    error AlreadyInitialized();
+    error NotInitialized();
+    error ContractFrozen();
+    error MissingRole(bytes32 role, bytes account);
+    error NoPendingTransfer(bytes32 role, bytes account);
+    error InvalidAccount(bytes account);
+    error RelayerNotApproved(bytes relayer);
+    error LayoutVersionMismatch(uint32 expected, uint32 found);
+    error InvalidCalldata();
+    error TrustedHeaderNotFound(uint64 latestBlock);
+    error TrustedBlockMismatch(uint64 latestBlock, uint64 trustedBlock);
+    error TrustedHeaderMismatch(bytes32 expected, bytes32 found);
+    error TargetBlockNotInRange(uint64 latestBlock, uint64 targetBlock);
+    error TargetBlockTooFar(uint64 targetBlock, uint64 maxBlock);
+    error EmptyBatch();
+    error InvalidPublicValues();
+    error InvalidProof();
+    error InvalidProofNonce(uint256 proofNonce);
+    error KeyNotInTree(uint256 key, uint256 numLeaves);
+    error InvalidMerkleProof();
+    error InvalidLeafRange(uint256 beginKey, uint256 endKey);
+    error InvalidLeafKeys();
+    error LeavesCountMismatch(uint256 expected, uint256 found);
+    error EmptySharesProof();
+    error RowProofsCountMismatch(uint256 expected, uint256 found);
+    error ShareProofsCountMismatch(uint256 expected, uint256 found);
+    error SharesCountMismatch(uint256 expected, uint256 found);
+    error InvalidShareRange(uint256 beginKey, uint256 endKey);
+    error InvalidNamespaceMerkleProof();
+    event DataCommitmentStored(uint256 proofNonce, uint64 indexed startBlock, uint64 indexed endBlock, bytes32 indexed dataCommitment);
+    event FreezeUpdated(bool frozen);
+    event HeadUpdate(uint64 blockNumber, bytes32 headerHash);
+    event ProgramVkeyUpdated(bytes programVkeyHash);
+    event RoleGranted(bytes32 indexed role, bytes account);
+    event RoleRevoked(bytes32 indexed role, bytes account);
+    event RoleTransferStarted(bytes32 indexed role, bytes from, bytes to);
+    event RelayerApprovalUpdated(bytes relayer, bool approved);
+    event CheckRelayerUpdated(bool checkRelayer);
+    event LayoutMigrated(uint32 fromVersion, uint32 toVersion);
+    function initializer(uint64 height, bytes32 header, bytes calldata blobstreamProgramVKeyHash, bytes calldata blobstreamProgramVKey) external; 
+    function updateFreeze(bool freeze) external; 
+    function updateGenesisState(uint64 height, bytes32 header) external;  
+    function updateProgramVkey(bytes calldata blobstreamProgramVKeyHash, bytes calldata blobstreamProgramVKey) external;  
+    function transferGuardian(bytes calldata newGuardian) external;
+    function acceptGuardian() external;
+    function grantRole(bytes32 role, bytes calldata account) external;
+    function revokeRole(bytes32 role, bytes calldata account) external;
+    function renounceRole(bytes32 role) external;
+    function migrateRoles() external;
+    function setRelayerApproval(bytes calldata relayer, bool approved) external;
+    function setCheckRelayer(bool checkRelayer) external;
+    function commitHeaderRange(bytes calldata proof, bytes calldata publicValues) external;  
+    function commitHeaderRanges(CommitHeaderRangeInput[] calldata inputs) external;
+    function latestBlock() external view returns (uint64);
+    function stateProofNonce() external view returns (uint256);
+    function dataCommitment(uint256 proofNonce) external view returns (bytes32);
+    function blockHeightToHeaderHash(uint64 height) external view returns (bytes32);
+    function frozen() external view returns (bool);
+    function guardian() external view returns (bytes memory);
+    function roleMembers(bytes32 role) external view returns (bytes[] memory);
+    function programVkeyHash() external view returns (bytes memory);
+    function verifyAttestation(uint256 proofNonce, DataRootTuple calldata tuple, BinaryMerkleProof calldata proof) external view returns (bool);
+    function verifyAttestationRange(uint256 proofNonce, DataRootTuple[] calldata tuples, BinaryMerkleRangeProof calldata proof) external view returns (bool);
+    function verifyAttestationMulti(uint256 proofNonce, DataRootTuple[] calldata tuples, BinaryMerkleMultiproof calldata proof) external view returns (bool);
+    function verifyAttestations(VAInput[] calldata inputs) external view returns (bytes memory);
+    function verifySharesToDataRootTuple(SharesProof calldata proof) external view returns (bool);
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use crate::error::{Error, InvalidTreeSize, LeafNotInTree, ProofLengthMismatch};
+use crate::FixedBytes;
+use sha2::Digest;
+
+// Merkle tree of the data root and state root commitments, as computed by the VectorX circuits.
+// The leaves are the roots of the blocks in the range, padded with zero leaves to the tree size,
+// and a node is the sha256 hash of its children.
+
+/// Verifies that `leaf` is the leaf at `index` of the tree with `tree_size` leaves and root `root`.
+/// `side_nodes` are the siblings on the path from the leaf to the root, bottom up.
+/// Returns an error if the proof is malformed, and false if the proof is for a different root.
+pub fn verify(
+    root: FixedBytes<32>,
+    leaf: FixedBytes<32>,
+    index: u32,
+    tree_size: u32,
+    side_nodes: &[FixedBytes<32>],
+) -> Result<bool, Error> {
+    let depth = depth(tree_size)?;
+    if index >= tree_size {
+        return Err(Error::LeafNotInTree(LeafNotInTree {
+            index,
+            treeSize: tree_size,
+        }));
+    }
+    if side_nodes.len() != depth as usize {
+        return Err(Error::ProofLengthMismatch(ProofLengthMismatch {
+            expected: depth,
+            found: side_nodes.len() as u32,
+        }));
+    }
+
+    let mut node = leaf;
+    let mut index = index;
+    for side_node in side_nodes {
+        node = if index & 1 == 0 {
+            node_hash(&node, side_node)
+        } else {
+            node_hash(side_node, &node)
+        };
+        index /= 2;
+    }
+    Ok(node == root)
+}
+
+/// Returns the root of the tree with `tree_size` leaves, `leaves` followed by zero leaves.
+pub fn root(leaves: &[FixedBytes<32>], tree_size: u32) -> Result<FixedBytes<32>, Error> {
+    let mut level = padded_leaves(leaves, tree_size)?;
+    while level.len() > 1 {
+        level = level
+            .chunks_exact(2)
+            .map(|pair| node_hash(&pair[0], &pair[1]))
+            .collect();
+    }
+    Ok(level[0])
+}
+
+/// Returns the side nodes proving the leaf at `index` of the tree with `tree_size` leaves, `leaves` followed by zero leaves.
+pub fn prove(
+    leaves: &[FixedBytes<32>],
+    index: u32,
+    tree_size: u32,
+) -> Result<Vec<FixedBytes<32>>, Error> {
+    let mut level = padded_leaves(leaves, tree_size)?;
+    if index >= tree_size {
+        return Err(Error::LeafNotInTree(LeafNotInTree {
+            index,
+            treeSize: tree_size,
+        }));
+    }
+    let mut index = index as usize;
+    let mut side_nodes = Vec::new();
+    while level.len() > 1 {
+        side_nodes.push(level[index ^ 1]);
+        level = level
+            .chunks_exact(2)
+            .map(|pair| node_hash(&pair[0], &pair[1]))
+            .collect();
+        index /= 2;
+    }
+    Ok(side_nodes)
+}
+
+/// Returns the number of levels below the root, an error if the tree size is not a power of two.
+fn depth(tree_size: u32) -> Result<u32, Error> {
+    if !tree_size.is_power_of_two() {
+        return Err(Error::InvalidTreeSize(InvalidTreeSize {
+            treeSize: tree_size,
+        }));
+    }
+    Ok(tree_size.trailing_zeros())
+}
+
+/// Returns the leaves padded with zero leaves to the tree size.
+fn padded_leaves(leaves: &[FixedBytes<32>], tree_size: u32) -> Result<Vec<FixedBytes<32>>, Error> {
+    depth(tree_size)?;
+    if leaves.len() > tree_size as usize {
+        return Err(Error::LeafNotInTree(LeafNotInTree {
+            index: leaves.len() as u32 - 1,
+            treeSize: tree_size,
+        }));
+    }
+    let mut padded = leaves.to_vec();
+    padded.resize(tree_size as usize, FixedBytes::ZERO);
+    Ok(padded)
+}
+
+/// Returns the sha256 hash of the children.
+fn node_hash(left: &FixedBytes<32>, right: &FixedBytes<32>) -> FixedBytes<32> {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    FixedBytes::new(hasher.finalize().into())
+}
@@ -0,0 +1,115 @@
+use crate::sol;
+use seq_wasm_sdk::access_control::AccessControlError;
+use seq_wasm_sdk::migration::MigrationError;
+
+sol! {
+    /// Errors a call to the contract reverts with.
+    #[derive(Debug, PartialEq, Eq)]
+    interface IVector {
+        /// The contract is already initialized.
+        error AlreadyInitialized();
+        /// The contract is not initialized yet.
+        error NotInitialized();
+        /// The contract is frozen by the guardian.
+        error ContractFrozen();
+        /// The account does not have the role.
+        error MissingRole(bytes32 role, bytes account);
+        /// No transfer of the role to the account is pending.
+        error NoPendingTransfer(bytes32 role, bytes account);
+        /// The bytes are not a valid address.
+        error InvalidAccount(bytes account);
+        /// The relayer check is enabled and msg_sender is not an approved relayer.
+        error RelayerNotApproved(bytes relayer);
+        /// The state has another layout version than the call expects.
+        error LayoutVersionMismatch(uint32 expected, uint32 found);
+        /// The calldata can not be ABI decoded into the parameters of the function.
+        error InvalidCalldata();
+        /// The block range data is empty, or its arrays differ in length.
+        error InvalidInputLength();
+        /// The first start block is not the latest block.
+        error StartBlockMismatch(uint32 latestBlock, uint32 startBlock);
+        /// An end block is not the start block of the following range.
+        error InvalidBlockRange(uint32 endBlock, uint32 nextStartBlock);
+        /// The public values are of a different proof type.
+        error InvalidProofType();
+        /// The public values can not be decoded.
+        error InvalidPublicValues();
+        /// The merkle tree size of the proof is not the header range commitment tree size.
+        error MerkleTreeSizeMismatch(uint32 expected, uint32 found);
+        /// No header hash is stored for the latest block.
+        error TrustedHeaderNotFound(uint32 latestBlock);
+        /// The trusted block of the proof is not the latest block.
+        error TrustedBlockMismatch(uint32 latestBlock, uint32 trustedBlock);
+        /// The trusted header of the proof is not the header of the latest block.
+        error TrustedHeaderMismatch(bytes32 expected, bytes32 found);
+        /// No authority set hash is stored for the authority set id.
+        error AuthoritySetNotFound(uint64 authoritySetId);
+        /// The authority set hash of the proof is not the stored authority set hash.
+        error AuthoritySetHashMismatch(bytes32 expected, bytes32 found);
+        /// The target block is not after the latest block.
+        error TargetBlockNotInRange(uint32 latestBlock, uint32 targetBlock);
+        /// The block is not in the block range (startBlock, endBlock].
+        error BlockNotInRange(uint32 blockNumber, uint32 startBlock, uint32 endBlock);
+        /// No commitment is stored for the block range (startBlock, endBlock].
+        error RangeCommitmentNotFound(uint32 startBlock, uint32 endBlock);
+        /// The commitment tree size is not a power of two.
+        error InvalidTreeSize(uint32 treeSize);
+        /// The leaf index is not less than the commitment tree size.
+        error LeafNotInTree(uint32 index, uint32 treeSize);
+        /// The number of side nodes is not the depth of the commitment tree.
+        error ProofLengthMismatch(uint32 expected, uint32 found);
+        /// The authority set id is older than the latest authority set id.
+        error OldAuthoritySetId(uint64 latestAuthoritySetId, uint64 authoritySetId);
+        /// The authority set hash of the next authority set id is already stored.
+        error NextAuthoritySetExists(uint64 authoritySetId);
+        /// The proof was rejected by the verifier.
+        error InvalidProof();
+    }
+}
+
+/// Reasons a call to the contract fails.
+pub use IVector::IVectorErrors as Error;
+pub use IVector::{
+    AlreadyInitialized, AuthoritySetHashMismatch, AuthoritySetNotFound, BlockNotInRange,
+    ContractFrozen, InvalidAccount, InvalidBlockRange, InvalidCalldata, InvalidInputLength,
+    InvalidProof, InvalidProofType, InvalidPublicValues, InvalidTreeSize, LayoutVersionMismatch,
+    LeafNotInTree, MerkleTreeSizeMismatch, MissingRole, NextAuthoritySetExists, NoPendingTransfer,
+    NotInitialized, OldAuthoritySetId, ProofLengthMismatch, RangeCommitmentNotFound,
+    RelayerNotApproved, StartBlockMismatch, TargetBlockNotInRange, TrustedBlockMismatch,
+    TrustedHeaderMismatch, TrustedHeaderNotFound,
+};
+
+/// Access control errors are declared by the contract with the same signatures.
+impl From<AccessControlError> for Error {
+    fn from(error: AccessControlError) -> Self {
+        match error {
+            AccessControlError::MissingRole(e) => Error::MissingRole(MissingRole {
+                role: e.role,
+                account: e.account,
+            }),
+            AccessControlError::NoPendingTransfer(e) => {
+                Error::NoPendingTransfer(NoPendingTransfer {
+                    role: e.role,
+                    account: e.account,
+                })
+            }
+            AccessControlError::InvalidAccount(e) => {
+                Error::InvalidAccount(InvalidAccount { account: e.account })
+            }
+        }
+    }
+}
+
+/// Migration errors are declared by the contract with the same signatures.
+impl From<MigrationError> for Error {
+    fn from(error: MigrationError) -> Self {
+        match error {
+            MigrationError::LayoutVersionMismatch(e) => {
+                Error::LayoutVersionMismatch(LayoutVersionMismatch {
+                    expected: e.expected,
+                    found: e.found,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::sol;
+
+sol! {
+    /// Emitted when the header of a block is trusted, by a header range commitment or the guardian.
+    #[derive(Debug, PartialEq, Eq)]
+    event HeadUpdate(uint32 blockNumber, bytes32 headerHash);
+
+    /// Emitted when the data and state commitments for the block range (startBlock, endBlock] are stored.
+    #[derive(Debug, PartialEq, Eq)]
+    event HeaderRangeCommitmentStored(
+        uint32 startBlock,
+        uint32 endBlock,
+        bytes32 dataCommitment,
+        bytes32 stateCommitment,
+        uint32 headerRangeCommitmentTreeSize
+    );
+
+    /// Emitted when the authority set hash of an authority set id is stored.
+    #[derive(Debug, PartialEq, Eq)]
+    event AuthoritySetStored(uint64 authoritySetId, bytes32 authoritySetHash);
+
+    /// Emitted when the guardian updates the header range commitment tree size.
+    #[derive(Debug, PartialEq, Eq)]
+    event UpdatedHeaderRangeCommitmentTreeSize(uint32 oldSize, uint32 newSize);
+
+    /// Emitted when the guardian freezes or unfreezes the contract.
+    #[derive(Debug, PartialEq, Eq)]
+    event FreezeUpdated(bool frozen);
+
+    /// Emitted when the guardian updates the program vkey.
+    #[derive(Debug, PartialEq, Eq)]
+    event ProgramVkeyUpdated(bytes programVkeyHash);
+
+    /// Emitted when the guardian approves or removes a relayer.
+    #[derive(Debug, PartialEq, Eq)]
+    event RelayerApprovalUpdated(bytes relayer, bool approved);
+
+    /// Emitted when the guardian enables or disables the check for approved relayers.
+    #[derive(Debug, PartialEq, Eq)]
+    event CheckRelayerUpdated(bool checkRelayer);
+}
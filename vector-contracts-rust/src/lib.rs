@@ -1,286 +1,420 @@
-#![cfg_attr(debug_assertions, allow(dead_code, unused_imports, unused_variables))]
+pub mod commitment_tree;
+pub mod error;
+pub mod events;
 pub mod input_type;
 
 // crate imports.
-use input_type::{
-    CommitHeaderRangeAndRotateInput, HeaderRangeOutputs, InitializerInput, KeyPacker, ProofType,
-    RotateOutputs, UpdateBlockRangeDataInput, UpdateCommitmentTreeSizeInput, UpdateFreezeInput,
-    UpdateGenesisStateInput, UpdateVectorProgramVkeyInput,
+use error::*;
+use events::{
+    AuthoritySetStored, CheckRelayerUpdated, FreezeUpdated, HeadUpdate,
+    HeaderRangeCommitmentStored, ProgramVkeyUpdated, RelayerApprovalUpdated,
+    UpdatedHeaderRangeCommitmentTreeSize,
 };
+use input_type::{HeaderRangeOutputs, KeyPacker, ProofOutputs, ProofType, RotateOutputs};
 
 // seq wasm sdk
+use seq_wasm_sdk::access_control::{self, Role, ADMIN, GUARDIAN};
 pub use seq_wasm_sdk::allocator::*;
-use seq_wasm_sdk::{keccak256, slice, sol, FixedBytes, SolType, SolValue};
-use seq_wasm_sdk::{precompiles, state, utils::TxContext};
-use seq_wasm_sdk_macros::public;
-
-// state variables
-const STATIC_ISINITIALIZED: u32 = 0;
-const STATIC_FROZEN: u32 = 1;
-const STATIC_GUARDIAN: u32 = 2;
-const STATIC_LATESTBLOCK: u32 = 3;
-const STATIC_LATEST_AUTHORITY_SET_ID: u32 = 4;
-const STATIC_HEADER_RANGE_COMMITMENT_TREE_SIZE: u32 = 5;
-const STATIC_VECTOR_PROGRAM_VKEY_HASH: u32 = 6; // hash of vk produced for vectorx ELF.
-const STATIC_VECTOR_PROGRAM_VKEY: u32 = 7; // actual verification key.
-
-// ids for storing dynamic variables.
-const MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID: u32 = 1;
-const MAPPING_AUTHORITY_SET_ID_TO_HASH_ID: u32 = 2;
-const MAPPING_DATA_ROOT_COMMITMENTS_ID: u32 = 3;
-const MAPPING_STATE_ROOT_COMMITMENTS_ID: u32 = 4;
-const MAPPING_RANGE_START_BLOCKS_ID: u32 = 5;
+use seq_wasm_sdk::events::Event;
+use seq_wasm_sdk::migration;
+use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+use seq_wasm_sdk::{keccak256, sol, Bytes, FixedBytes, SolValue};
+use seq_wasm_sdk::{precompiles, types::Address, utils::TxContext};
+use seq_wasm_sdk_macros::{public, storage};
+
+// state layout of the contract, slots and mapping ids are assigned in order of declaration.
+#[storage]
+struct Vector {
+    is_initialized: StorageValue<bool>,
+    frozen: StorageValue<bool>,
+    /// guardian of layout version 0, moved to the `access_control` roles by `migrate_roles`.
+    legacy_guardian: StorageValue<Address>,
+    latest_block: StorageValue<u32>,
+    latest_authority_set_id: StorageValue<u64>,
+    header_range_commitment_tree_size: StorageValue<u32>,
+    /// hash of vk produced for vectorx ELF.
+    program_vkey_hash: StorageValue<Vec<u8>>,
+    /// actual verification key.
+    program_vkey: StorageValue<Vec<u8>>,
+    header_hashes: StorageMap<u32, FixedBytes<32>>,
+    authority_set_hashes: StorageMap<u64, FixedBytes<32>>,
+    data_root_commitments: StorageMap<FixedBytes<32>, FixedBytes<32>>,
+    state_root_commitments: StorageMap<FixedBytes<32>, FixedBytes<32>>,
+    range_start_blocks: StorageMap<FixedBytes<32>, u32>,
+    /// when true, only approved relayers can submit proofs.
+    check_relayer: StorageValue<bool>,
+    approved_relayers: StorageMap<Address, bool>,
+    /// commitment tree size the block range was committed with, absent for ranges committed before it was stored.
+    range_tree_sizes: StorageMap<FixedBytes<32>, u32>,
+}
+
+// CONSTANT VARIABLES
+/// Layout version of the state written by this code, see `migrate_roles`.
+const LAYOUT_VERSION: u32 = 1;
 
 #[public]
 /// This function initializes the contract with the initial state variables.
 /// This function can only be called once.
 /// Called during the contract deployment.
-pub fn initializer() {
+pub fn initializer(
+    height: u32,
+    header: FixedBytes<32>,
+    authority_set_id: u64,
+    authority_set_hash: FixedBytes<32>,
+    header_range_commitment_tree_size: u32,
+    vector_program_vkey_hash: Bytes,
+    vector_program_vkey: Bytes,
+) -> Result<(), Error> {
     if is_initialized() {
         // contract already initialized
-        return false;
+        return Err(Error::AlreadyInitialized(AlreadyInitialized {}));
     }
 
-    // Decode msg_sender from tx_context and inputs from IntializerInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let (
-        height,
-        header,
-        authority_set_id,
-        authority_set_hash,
-        header_range_commitment_tree_size,
-        vector_program_vkey_hash,
-        vector_program_vkey,
-    ) = InitializerInput::new(ptr, len).unpack();
-
     // Store the initial state variables and set contract as initialized.
-    state::store_mapping_u32_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, height, header);
-    state::store_mapping_u64_bytes32(
-        MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-        authority_set_id,
-        authority_set_hash,
-    );
-    state::store_u64(STATIC_LATEST_AUTHORITY_SET_ID, authority_set_id);
-    state::store_u32(STATIC_LATESTBLOCK, height);
-    state::store_vec(STATIC_VECTOR_PROGRAM_VKEY_HASH, &vector_program_vkey_hash);
-    state::store_vec(STATIC_VECTOR_PROGRAM_VKEY, &vector_program_vkey);
-    state::store_u32(
-        STATIC_HEADER_RANGE_COMMITMENT_TREE_SIZE,
-        header_range_commitment_tree_size,
-    );
-
-    state::store_address(STATIC_GUARDIAN, &msg_sender);
-    state::store_bool(STATIC_ISINITIALIZED, 1);
-
-    // Call executed without any errors, return true.
-    true
+    Vector::header_hashes().insert(height, header);
+    Vector::authority_set_hashes().insert(authority_set_id, authority_set_hash);
+    Vector::latest_authority_set_id().set(authority_set_id);
+    Vector::latest_block().set(height);
+    Vector::program_vkey_hash().set(vector_program_vkey_hash.to_vec());
+    Vector::program_vkey().set(vector_program_vkey.to_vec());
+    Vector::header_range_commitment_tree_size().set(header_range_commitment_tree_size);
+
+    access_control::grant_role_unchecked(ADMIN, &msg_sender);
+    access_control::grant_role_unchecked(GUARDIAN, &msg_sender);
+    migration::set_layout_version(LAYOUT_VERSION);
+    Vector::is_initialized().set(true);
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the guardian can set the contract to a frozen state.
-pub fn update_freeze() {
-    // Decode msg_sender from tx_context and inputs from UpdateFreezeInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let owner = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != owner {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
+pub fn update_freeze(freeze: bool) -> Result<(), Error> {
     // msg_sender is the guardian, update the freeze state variable.
-    let freeze = UpdateFreezeInput::new(ptr, len).freeze;
-    state::store_bool(STATIC_FROZEN, freeze as u32);
-
-    // Call executed without any errors, return true.
-    true
+    Vector::frozen().set(freeze);
+    FreezeUpdated { frozen: freeze }.emit();
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the guardian can update the program vkey.
-pub fn update_vector_program_vkey() {
-    // Decode msg_sender from tx_context and inputs from UpdateVectorProgramVKeyInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let (program_vkey_hash, program_vkey) = UpdateVectorProgramVkeyInput::new(ptr, len).unpack();
-
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
+pub fn update_vector_program_vkey(
+    program_vkey_hash: Bytes,
+    program_vkey: Bytes,
+) -> Result<(), Error> {
     // msg_sender is the guardian, update program vkey.
-    state::store_vec(STATIC_VECTOR_PROGRAM_VKEY_HASH, &program_vkey_hash);
-    state::store_vec(STATIC_VECTOR_PROGRAM_VKEY, &program_vkey);
-
-    // Call executed without any errors, return true.
-    true
+    Vector::program_vkey_hash().set(program_vkey_hash.to_vec());
+    Vector::program_vkey().set(program_vkey.to_vec());
+    ProgramVkeyUpdated {
+        programVkeyHash: program_vkey_hash,
+    }
+    .emit();
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the guardian can update the commitment tree size.
-pub fn update_commitment_tree_size() {
-    // Decode msg_sender from tx_context and inputs from UpdateCommitmentTreeSizeInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let header_range_commitment_tree_size = UpdateCommitmentTreeSizeInput::new(ptr, len).unpack();
-
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
+pub fn update_commitment_tree_size(header_range_commitment_tree_size: u32) -> Result<(), Error> {
     // msg_sender is the gaurdian, update the commitment tree size.
-    state::store_u32(
-        STATIC_HEADER_RANGE_COMMITMENT_TREE_SIZE,
-        header_range_commitment_tree_size,
-    );
-
-    // Call executed without any errors, return true.
-    true
+    let old_size = Vector::header_range_commitment_tree_size().get();
+    Vector::header_range_commitment_tree_size().set(header_range_commitment_tree_size);
+    UpdatedHeaderRangeCommitmentTreeSize {
+        oldSize: old_size,
+        newSize: header_range_commitment_tree_size,
+    }
+    .emit();
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 /// Only the gaurdian can update the genesis state of the contract.
-pub fn update_genesis_state() {
-    // Decode msg_sender from tx_context and inputs from UpdateGenesisStateInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let (height, header, authority_set_id, authority_set_hash) =
-        UpdateGenesisStateInput::new(ptr, len).unpack();
-
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
+pub fn update_genesis_state(
+    height: u32,
+    header: FixedBytes<32>,
+    authority_set_id: u64,
+    authority_set_hash: FixedBytes<32>,
+) -> Result<(), Error> {
     // msg_sender is the guardian, update the genesis state variables.
-    state::store_mapping_u32_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, height, header);
-    state::store_u32(STATIC_LATESTBLOCK, height);
-    state::store_mapping_u64_bytes32(
-        MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-        authority_set_id,
-        authority_set_hash,
-    );
-    state::store_u64(STATIC_LATEST_AUTHORITY_SET_ID, authority_set_id);
-
-    // Call executed without any errors, return true.
-    true
+    Vector::header_hashes().insert(height, header);
+    Vector::latest_block().set(height);
+    Vector::authority_set_hashes().insert(authority_set_id, authority_set_hash);
+    Vector::latest_authority_set_id().set(authority_set_id);
+    HeadUpdate {
+        blockNumber: height,
+        headerHash: header,
+    }
+    .emit();
+    AuthoritySetStored {
+        authoritySetId: authority_set_id,
+        authoritySetHash: authority_set_hash,
+    }
+    .emit();
+    Ok(())
 }
 
 #[public]
+#[only_role(GUARDIAN)]
 // Only the guardian can update the block range data.
-pub fn update_block_range_data() {
-    // Decode msg_sender from tx_context and inputs from UpdateBlockRangeDataInput.
-    let msg_sender = TxContext::unpack(tx_context).msg_sender();
-    let (
-        start_blocks,
-        end_blocks,
-        header_hashes,
-        data_root_commitments,
-        state_root_commitments,
-        end_authority_set_id,
-        end_authority_set_hash,
-    ) = UpdateBlockRangeDataInput::new(ptr, len).unpack();
-
-    // Fetch the guardian address from the state and check if the msg_sender is the guardian.
-    let gaurdian = state::get_address(STATIC_GUARDIAN);
-    if msg_sender != gaurdian {
-        // msg_sender is not the guardian, return false.
-        return false;
-    }
-
-    // msg_sender is the guardian.
-
+pub fn update_block_range_data(
+    start_blocks: Vec<u32>,
+    end_blocks: Vec<u32>,
+    header_hashes: Vec<FixedBytes<32>>,
+    data_root_commitments: Vec<FixedBytes<32>>,
+    state_root_commitments: Vec<FixedBytes<32>>,
+    end_authority_set_id: u64,
+    end_authority_set_hash: FixedBytes<32>,
+) -> Result<(), Error> {
     // sanity check for the input lengths.
-    if !(start_blocks.len() > 0
-        && start_blocks.len() == end_blocks.len()
-        && end_blocks.len() == header_hashes.len()
-        && header_hashes.len() == data_root_commitments.len()
-        && data_root_commitments.len() == state_root_commitments.len())
+    if start_blocks.is_empty()
+        || start_blocks.len() != end_blocks.len()
+        || end_blocks.len() != header_hashes.len()
+        || header_hashes.len() != data_root_commitments.len()
+        || data_root_commitments.len() != state_root_commitments.len()
     {
-        return false;
+        return Err(Error::InvalidInputLength(InvalidInputLength {}));
     }
 
-    let latest_block = state::get_u32(STATIC_LATESTBLOCK);
+    let latest_block = Vector::latest_block().get();
     if start_blocks[0] != latest_block {
         // start block is not the latest block.
-        return false;
+        return Err(Error::StartBlockMismatch(StartBlockMismatch {
+            latestBlock: latest_block,
+            startBlock: start_blocks[0],
+        }));
     }
 
+    let tree_size = Vector::header_range_commitment_tree_size().get();
     for i in 0..start_blocks.len() {
-        if i < start_blocks.len() - 1 {
-            if !(end_blocks[i] == start_blocks[i + 1]) {
-                // invalid block range.
-                return false;
-            }
+        if i < start_blocks.len() - 1 && (end_blocks[i] != start_blocks[i + 1]) {
+            // invalid block range.
+            return Err(Error::InvalidBlockRange(InvalidBlockRange {
+                endBlock: end_blocks[i],
+                nextStartBlock: start_blocks[i + 1],
+            }));
         }
 
-        // key is the keccak256 hash of the latest block and target block.
-        let key = keccak256(
-            KeyPacker {
-                latestBlock: start_blocks[i],
-                targetBlock: end_blocks[i],
-            }
-            .abi_encode(),
-        );
+        let key = range_key(start_blocks[i], end_blocks[i]);
 
         // store the data and state commitments for the block range.
-        state::store_mapping_bytes32_bytes32(
-            MAPPING_DATA_ROOT_COMMITMENTS_ID,
-            key,
-            data_root_commitments[i],
-        );
-        state::store_mapping_bytes32_bytes32(
-            MAPPING_STATE_ROOT_COMMITMENTS_ID,
-            key,
-            state_root_commitments[i],
-        );
-
-        // store the range start blocks.
-        state::store_mapping_bytes32_u32(MAPPING_RANGE_START_BLOCKS_ID, key, start_blocks[i]);
-        state::store_mapping_u32_bytes32(
-            MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID,
-            end_blocks[i],
-            header_hashes[i],
-        );
+        Vector::data_root_commitments().insert(key, data_root_commitments[i]);
+        Vector::state_root_commitments().insert(key, state_root_commitments[i]);
+
+        // store the range start blocks and the commitment tree size.
+        Vector::range_start_blocks().insert(key, start_blocks[i]);
+        Vector::range_tree_sizes().insert(key, tree_size);
+        Vector::header_hashes().insert(end_blocks[i], header_hashes[i]);
+
+        HeaderRangeCommitmentStored {
+            startBlock: start_blocks[i],
+            endBlock: end_blocks[i],
+            dataCommitment: data_root_commitments[i],
+            stateCommitment: state_root_commitments[i],
+            headerRangeCommitmentTreeSize: tree_size,
+        }
+        .emit();
     }
 
     // store latest block, end_authority_set_hash, and latest_authority_set_id.
-    state::store_u32(STATIC_LATESTBLOCK, end_blocks[end_blocks.len() - 1]);
-    state::store_mapping_u64_bytes32(
-        MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-        end_authority_set_id,
-        end_authority_set_hash,
-    );
-    state::store_u64(STATIC_LATEST_AUTHORITY_SET_ID, end_authority_set_id);
+    Vector::latest_block().set(end_blocks[end_blocks.len() - 1]);
+    Vector::authority_set_hashes().insert(end_authority_set_id, end_authority_set_hash);
+    Vector::latest_authority_set_id().set(end_authority_set_id);
+    HeadUpdate {
+        blockNumber: end_blocks[end_blocks.len() - 1],
+        headerHash: header_hashes[header_hashes.len() - 1],
+    }
+    .emit();
+    AuthoritySetStored {
+        authoritySetId: end_authority_set_id,
+        authoritySetHash: end_authority_set_hash,
+    }
+    .emit();
+    Ok(())
+}
+
+#[public]
+#[only_role(GUARDIAN)]
+/// Only the guardian can approve or remove a relayer.
+pub fn set_relayer_approval(relayer: Bytes, approved: bool) -> Result<(), Error> {
+    let relayer_address = access_control::parse_account(&relayer)?;
+    Vector::approved_relayers().insert(relayer_address, approved);
+    RelayerApprovalUpdated { relayer, approved }.emit();
+    Ok(())
+}
+
+#[public]
+#[only_role(GUARDIAN)]
+/// Only the guardian can enable or disable the check for approved relayers.
+pub fn set_check_relayer(check_relayer: bool) -> Result<(), Error> {
+    Vector::check_relayer().set(check_relayer);
+    CheckRelayerUpdated {
+        checkRelayer: check_relayer,
+    }
+    .emit();
+    Ok(())
+}
+
+#[public]
+#[migrate(from = 0)]
+/// Moves the guardian of layout version 0 to the `ADMIN` and `GUARDIAN` roles, only the guardian can migrate.
+pub fn migrate_roles() -> Result<(), Error> {
+    // roles do not exist before the migration, the guardian is read from its slot of layout version 0.
+    if Vector::legacy_guardian().try_get() != Ok(Some(msg_sender.clone())) {
+        return Err(Error::MissingRole(MissingRole {
+            role: GUARDIAN,
+            account: msg_sender.as_bytes().to_vec().into(),
+        }));
+    }
+    access_control::grant_role_unchecked(ADMIN, &msg_sender);
+    access_control::grant_role_unchecked(GUARDIAN, &msg_sender);
+    Vector::legacy_guardian().clear();
+    Ok(())
+}
+
+#[public]
+/// Starts the transfer of the guardian role to `new_guardian`, which takes effect once it is accepted.
+pub fn transfer_guardian(new_guardian: Bytes) -> Result<(), Error> {
+    let new_guardian = access_control::parse_account(&new_guardian)?;
+    access_control::transfer_role(&msg_sender, GUARDIAN, &new_guardian)?;
+    Ok(())
+}
+
+#[public]
+/// Accepts the pending transfer of the guardian role to msg_sender.
+pub fn accept_guardian() -> Result<(), Error> {
+    access_control::accept_role(&msg_sender, GUARDIAN)?;
+    Ok(())
+}
+
+#[public]
+/// Only an admin can grant a role.
+pub fn grant_role(role: Role, account: Bytes) -> Result<(), Error> {
+    let account = access_control::parse_account(&account)?;
+    access_control::grant_role(&msg_sender, role, &account)?;
+    Ok(())
+}
+
+#[public]
+/// Only an admin can revoke a role.
+pub fn revoke_role(role: Role, account: Bytes) -> Result<(), Error> {
+    let account = access_control::parse_account(&account)?;
+    access_control::revoke_role(&msg_sender, role, &account)?;
+    Ok(())
+}
+
+#[public]
+/// Revokes a role from msg_sender.
+pub fn renounce_role(role: Role) -> Result<(), Error> {
+    access_control::renounce_role(&msg_sender, role)?;
+    Ok(())
+}
+
+#[public]
+/// Returns the id of the latest authority set.
+pub fn latest_authority_set_id() -> u64 {
+    Vector::latest_authority_set_id().get()
+}
 
-    // Call executed without any errors, return true.
-    true
+#[public]
+/// Returns the hash of the authority set, zero if there is none.
+pub fn authority_set_hash(authority_set_id: u64) -> FixedBytes<32> {
+    Vector::authority_set_hashes().get(authority_set_id)
+}
+
+#[public]
+/// Returns the data root commitment for the block range (start_block, end_block], zero if there is none.
+pub fn data_root_commitment(start_block: u32, end_block: u32) -> FixedBytes<32> {
+    Vector::data_root_commitments().get(range_key(start_block, end_block))
+}
+
+#[public]
+/// Returns the state root commitment for the block range (start_block, end_block], zero if there is none.
+pub fn state_root_commitment(start_block: u32, end_block: u32) -> FixedBytes<32> {
+    Vector::state_root_commitments().get(range_key(start_block, end_block))
+}
+
+#[public]
+/// Returns the start block of the block range with the commitment key, zero if there is none.
+pub fn range_start_block(key: FixedBytes<32>) -> u32 {
+    Vector::range_start_blocks().get(key)
+}
+
+#[public]
+/// Returns the trusted header hash of the block, zero if there is none.
+pub fn block_height_to_header_hash(height: u32) -> FixedBytes<32> {
+    Vector::header_hashes().get(height)
+}
+
+#[public]
+/// Returns the number of leaves of the header range commitment tree.
+pub fn header_range_commitment_tree_size() -> u32 {
+    Vector::header_range_commitment_tree_size().get()
+}
+
+#[public]
+/// Returns true if the data root of the block is included in the data root commitment of the
+/// block range (start_block, end_block], given the side nodes of the commitment tree proof.
+pub fn verify_data_root_inclusion(
+    block_number: u32,
+    data_root: FixedBytes<32>,
+    start_block: u32,
+    end_block: u32,
+    proof: Vec<FixedBytes<32>>,
+) -> Result<bool, Error> {
+    when_active()?;
+    verify_range_inclusion(
+        Vector::data_root_commitments(),
+        block_number,
+        data_root,
+        start_block,
+        end_block,
+        &proof,
+    )
+}
+
+#[public]
+/// Returns true if the state root of the block is included in the state root commitment of the
+/// block range (start_block, end_block], given the side nodes of the commitment tree proof.
+pub fn verify_state_root_inclusion(
+    block_number: u32,
+    state_root: FixedBytes<32>,
+    start_block: u32,
+    end_block: u32,
+    proof: Vec<FixedBytes<32>>,
+) -> Result<bool, Error> {
+    when_active()?;
+    verify_range_inclusion(
+        Vector::state_root_commitments(),
+        block_number,
+        state_root,
+        start_block,
+        end_block,
+        &proof,
+    )
 }
 
 #[public]
 /// Add target header hash, and data + state commitments for (latestBlock, targetBlock].
 /// The trusted block and requested block must have the same authority set id. If the target
 /// block is greater than the max batch size of the circuit, the proof will fail to generate.
-pub fn commit_header_range() {
-    // unpack proof and public values from CommitHeaderRangeInput.
-    let (proof, public_values) = CommitHeaderRangeAndRotateInput::new(ptr, len).unpack();
-    let (proof_type, header_range_outputs, _) =
-        CommitHeaderRangeAndRotateInput::new(ptr, len).unpack_po();
+pub fn commit_header_range(proof: Bytes, public_values: Bytes) -> Result<(), Error> {
+    // if the relayer check is enabled, only approved relayers can submit proofs.
+    only_approved_relayer(&msg_sender)?;
 
-    // if proof type is not HeaderRangeProof, return false.
-    if proof_type != ProofType::HeaderRangeProof {
-        return false;
-    }
+    // decode the proof outputs from public values.
+    let po = ProofOutputs::abi_decode(&public_values, true)
+        .map_err(|_| Error::InvalidPublicValues(InvalidPublicValues {}))?;
 
-    // if contract is frozen or not initialized, return false.
-    if is_frozen() || !is_initialized() {
-        return false;
+    // if proof type is not HeaderRangeProof, the call fails.
+    if po.proofType != ProofType::HeaderRangeProof {
+        return Err(Error::InvalidProofType(InvalidProofType {}));
     }
 
+    // if contract is frozen or not initialized, the call fails.
+    when_active()?;
+
     // unpack the header range outputs.
     let (
         trusted_block,
@@ -292,193 +426,293 @@ pub fn commit_header_range() {
         state_root_commitment,
         data_root_commitment,
         merkle_tree_size,
-    ) = HeaderRangeOutputs::new(&header_range_outputs).unpack();
+    ) = HeaderRangeOutputs::abi_decode(&po.headerRangeOutputs, true)
+        .map_err(|_| Error::InvalidPublicValues(InvalidPublicValues {}))?
+        .unpack();
 
     // fetch header range merkle tree size from the state.
-    let header_range_merkle_tree_size = state::get_u32(STATIC_HEADER_RANGE_COMMITMENT_TREE_SIZE);
+    let header_range_merkle_tree_size = Vector::header_range_commitment_tree_size().get();
     if merkle_tree_size != header_range_merkle_tree_size {
         // invalid merkle tree size.
-        return false;
+        return Err(Error::MerkleTreeSizeMismatch(MerkleTreeSizeMismatch {
+            expected: header_range_merkle_tree_size,
+            found: merkle_tree_size,
+        }));
     }
 
     // fetch the latest block and trusted header hash from the state.
-    let latest_block = state::get_u32(STATIC_LATESTBLOCK);
-    let stored_trusted_header_hash =
-        state::get_mapping_u32_bytes32(MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID, latest_block);
-
-    // sanity check trusted header hash.
-    if stored_trusted_header_hash == FixedBytes::new([0; 32]) {
+    let latest_block = Vector::latest_block().get();
+    let stored_trusted_header_hash = match Vector::header_hashes().try_get(latest_block) {
+        Ok(Some(stored_trusted_header_hash)) => stored_trusted_header_hash,
         // trusted header not found.
-        return false;
+        _ => {
+            return Err(Error::TrustedHeaderNotFound(TrustedHeaderNotFound {
+                latestBlock: latest_block,
+            }))
+        }
+    };
+
+    // check if the trusted block and trusted header hash from state match the input.
+    if trusted_block != latest_block {
+        return Err(Error::TrustedBlockMismatch(TrustedBlockMismatch {
+            latestBlock: latest_block,
+            trustedBlock: trusted_block,
+        }));
     }
-
-    // check if the trusted header hash and trusted block from state matches the input.
-    if trusted_header_hash != stored_trusted_header_hash && trusted_block != latest_block {
-        return false;
+    if trusted_header_hash != stored_trusted_header_hash {
+        return Err(Error::TrustedHeaderMismatch(TrustedHeaderMismatch {
+            expected: stored_trusted_header_hash,
+            found: trusted_header_hash,
+        }));
     }
 
     // fetch the authority set hash for the authority set id from the state.
-    let stored_authority_set_hash =
-        state::get_mapping_u64_bytes32(MAPPING_AUTHORITY_SET_ID_TO_HASH_ID, authority_set_id);
-
-    // sanity check authority set hash.
-    if stored_authority_set_hash == FixedBytes::<32>::new([0; 32]) {
-        return false;
-    }
+    let stored_authority_set_hash = match Vector::authority_set_hashes().try_get(authority_set_id) {
+        Ok(Some(stored_authority_set_hash)) => stored_authority_set_hash,
+        // authority set hash not found.
+        _ => {
+            return Err(Error::AuthoritySetNotFound(AuthoritySetNotFound {
+                authoritySetId: authority_set_id,
+            }))
+        }
+    };
 
     // check if the authority set hash from the state matches the input.
     if authority_set_hash != stored_authority_set_hash {
-        return false;
+        return Err(Error::AuthoritySetHashMismatch(AuthoritySetHashMismatch {
+            expected: stored_authority_set_hash,
+            found: authority_set_hash,
+        }));
     }
 
-    // return false if target block is less than or equal to the latest block stored in state.
+    // the call fails if target block is less than or equal to the latest block stored in state.
     if target_block <= latest_block {
-        return false;
+        return Err(Error::TargetBlockNotInRange(TargetBlockNotInRange {
+            latestBlock: latest_block,
+            targetBlock: target_block,
+        }));
     }
 
     // fetch latest authority set id from the state.
-    let latest_authority_set_id = state::get_u64(STATIC_LATEST_AUTHORITY_SET_ID);
+    let latest_authority_set_id = Vector::latest_authority_set_id().get();
     if authority_set_id < latest_authority_set_id {
         // old authority set id.
-        return false;
+        return Err(Error::OldAuthoritySetId(OldAuthoritySetId {
+            latestAuthoritySetId: latest_authority_set_id,
+            authoritySetId: authority_set_id,
+        }));
     }
 
     // fetch the program vkey hash and program vkey from the state.
     let (program_vkey_hash, vkey) = get_vkey_hash_and_vkey();
     // verify sp1 plonk proof.
-    if precompiles::gnark_verify(program_vkey_hash, public_values, proof, vkey) {
-        // proof is valid. update the state variables.
-        if authority_set_id > latest_authority_set_id {
-            state::store_u64(STATIC_LATEST_AUTHORITY_SET_ID, authority_set_id);
-        }
-        // key is the keccak256 hash of the latest block and target block.
-        let key = keccak256(
-            KeyPacker {
-                latestBlock: trusted_block,
-                targetBlock: target_block,
-            }
-            .abi_encode(),
-        );
-
-        state::store_mapping_bytes32_bytes32(
-            MAPPING_DATA_ROOT_COMMITMENTS_ID,
-            key,
-            data_root_commitment,
-        );
-        state::store_mapping_bytes32_bytes32(
-            MAPPING_STATE_ROOT_COMMITMENTS_ID,
-            key,
-            state_root_commitment,
-        );
-        state::store_mapping_bytes32_u32(MAPPING_RANGE_START_BLOCKS_ID, key, latest_block);
-        state::store_mapping_u32_bytes32(
-            MAPPING_BLOCK_HEIGHT_TO_HEADER_HASH_ID,
-            target_block,
-            target_header_hash,
-        );
-        state::store_u32(STATIC_LATESTBLOCK, target_block);
-
-        // Call executed without any errors, return true.
-        true
-    } else {
-        // proof is invalid, return false.
-        false
+    if !precompiles::gnark_verify(
+        program_vkey_hash,
+        public_values.to_vec(),
+        proof.to_vec(),
+        vkey,
+    ) {
+        return Err(Error::InvalidProof(InvalidProof {}));
+    }
+
+    // proof is valid. update the state variables.
+    if authority_set_id > latest_authority_set_id {
+        Vector::latest_authority_set_id().set(authority_set_id);
+    }
+    let key = range_key(trusted_block, target_block);
+
+    Vector::data_root_commitments().insert(key, data_root_commitment);
+    Vector::state_root_commitments().insert(key, state_root_commitment);
+    Vector::range_start_blocks().insert(key, latest_block);
+    Vector::range_tree_sizes().insert(key, merkle_tree_size);
+    Vector::header_hashes().insert(target_block, target_header_hash);
+    Vector::latest_block().set(target_block);
+
+    HeadUpdate {
+        blockNumber: target_block,
+        headerHash: target_header_hash,
+    }
+    .emit();
+    HeaderRangeCommitmentStored {
+        startBlock: latest_block,
+        endBlock: target_block,
+        dataCommitment: data_root_commitment,
+        stateCommitment: state_root_commitment,
+        headerRangeCommitmentTreeSize: merkle_tree_size,
     }
+    .emit();
+    Ok(())
 }
 
 #[public]
 /// Adds the authority set hash for the next authority set id.
-pub fn rotate() {
-    // unpack proof and public values from CommitHeaderRangeInput.
-    let (proof, public_values) = CommitHeaderRangeAndRotateInput::new(ptr, len).unpack();
-    let (proof_type, _, rotate_outputs) =
-        CommitHeaderRangeAndRotateInput::new(ptr, len).unpack_po();
-
-    // if proof type is not RotateProof, return false.
-    if proof_type != ProofType::RotateProof {
+pub fn rotate(proof: Bytes, public_values: Bytes) -> Result<(), Error> {
+    // if the relayer check is enabled, only approved relayers can submit proofs.
+    only_approved_relayer(&msg_sender)?;
+
+    // decode the proof outputs from public values.
+    let po = ProofOutputs::abi_decode(&public_values, true)
+        .map_err(|_| Error::InvalidPublicValues(InvalidPublicValues {}))?;
+
+    // if proof type is not RotateProof, the call fails.
+    if po.proofType != ProofType::RotateProof {
         // invalid proof type.
-        return false;
+        return Err(Error::InvalidProofType(InvalidProofType {}));
     }
 
-    // if contract is frozen or not initialized, return false.
-    if is_frozen() || !is_initialized() {
-        return false;
-    }
+    // if contract is frozen or not initialized, the call fails.
+    when_active()?;
 
     // unpack the rotate outputs.
     let (current_authority_set_id, current_authority_set_hash, new_authority_set_hash) =
-        RotateOutputs::new(&rotate_outputs).unpack();
+        RotateOutputs::abi_decode(&po.rotateOutputs, true)
+            .map_err(|_| Error::InvalidPublicValues(InvalidPublicValues {}))?
+            .unpack();
 
     // fetch the authority set hash for the current authority set id from the state.
-    let stored_authority_set_hash = state::get_mapping_u64_bytes32(
-        MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-        current_authority_set_id,
-    );
-    // sanity check authority set hash.
-    if stored_authority_set_hash == FixedBytes::<32>::new([0; 32]) {
-        return false;
-    }
+    let stored_authority_set_hash =
+        match Vector::authority_set_hashes().try_get(current_authority_set_id) {
+            Ok(Some(stored_authority_set_hash)) => stored_authority_set_hash,
+            // authority set hash not found.
+            _ => {
+                return Err(Error::AuthoritySetNotFound(AuthoritySetNotFound {
+                    authoritySetId: current_authority_set_id,
+                }))
+            }
+        };
     // check if the authority set hash from the state matches the input.
     if current_authority_set_hash != stored_authority_set_hash {
-        return false;
+        return Err(Error::AuthoritySetHashMismatch(AuthoritySetHashMismatch {
+            expected: stored_authority_set_hash,
+            found: current_authority_set_hash,
+        }));
     }
 
-    // fetch the next authority set hash from the state.
-    let next_authority_set_hash = state::get_mapping_u64_bytes32(
-        MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-        current_authority_set_id + 1,
-    );
-
-    // return false if next authority set hash is not empty.
-    if next_authority_set_hash != FixedBytes::<32>::new([0; 32]) {
-        // next authority set exists
-        return false;
+    // the call fails if the next authority set hash is already stored.
+    match Vector::authority_set_hashes().try_get(current_authority_set_id + 1) {
+        Ok(None) => {}
+        // next authority set exists, or its stored value is corrupted.
+        _ => {
+            return Err(Error::NextAuthoritySetExists(NextAuthoritySetExists {
+                authoritySetId: current_authority_set_id + 1,
+            }))
+        }
     }
 
     // fetch the program vkey hash and program vkey from the state.
     let (program_vkey_hash, vkey) = get_vkey_hash_and_vkey();
     // verify sp1 plonk proof.
-    if precompiles::gnark_verify(program_vkey_hash, public_values, proof, vkey) {
-        // proof is valid. update the state variables.
-
-        // store the authority set hash for the next authority set id
-        state::store_mapping_u64_bytes32(
-            MAPPING_AUTHORITY_SET_ID_TO_HASH_ID,
-            current_authority_set_id + 1,
-            new_authority_set_hash,
-        );
+    if !precompiles::gnark_verify(
+        program_vkey_hash,
+        public_values.to_vec(),
+        proof.to_vec(),
+        vkey,
+    ) {
+        return Err(Error::InvalidProof(InvalidProof {}));
+    }
 
-        // Call executed without any errors, return true.
-        true
-    } else {
-        // proof is invalid. return false.
-        false
+    // proof is valid. store the authority set hash for the next authority set id
+    Vector::authority_set_hashes().insert(current_authority_set_id + 1, new_authority_set_hash);
+    AuthoritySetStored {
+        authoritySetId: current_authority_set_id + 1,
+        authoritySetHash: new_authority_set_hash,
     }
+    .emit();
+    Ok(())
 }
 
 // Helper functions
 
 /// Returns true if the contract is frozen, false otherwise.
 fn is_frozen() -> bool {
-    if state::get_bool(STATIC_FROZEN) == 1 {
-        true
-    } else {
-        false
-    }
+    Vector::frozen().get()
 }
 
 /// Returns true if the contract is initialized, false otherwise.
 fn is_initialized() -> bool {
-    if state::get_bool(STATIC_ISINITIALIZED) == 1 {
-        true
-    } else {
-        false
+    Vector::is_initialized().get()
+}
+
+/// Returns an error if the relayer check is enabled and `msg_sender` is not an approved relayer.
+fn only_approved_relayer(msg_sender: &Address) -> Result<(), Error> {
+    if Vector::check_relayer().get() && !Vector::approved_relayers().get(msg_sender.clone()) {
+        return Err(Error::RelayerNotApproved(RelayerNotApproved {
+            relayer: msg_sender.as_bytes().to_vec().into(),
+        }));
+    }
+    Ok(())
+}
+
+/// Returns an error if the contract is not initialized or frozen.
+fn when_active() -> Result<(), Error> {
+    if !is_initialized() {
+        return Err(Error::NotInitialized(NotInitialized {}));
     }
+    // state of an older layout has to be migrated first.
+    migration::only_layout_version(LAYOUT_VERSION)?;
+    if is_frozen() {
+        return Err(Error::ContractFrozen(ContractFrozen {}));
+    }
+    Ok(())
+}
+
+/// Returns true if `leaf` is the leaf of the block in the commitment of the block range (start_block, end_block] in `commitments`,
+/// the root of the commitment tree of the size the range was committed with.
+fn verify_range_inclusion(
+    commitments: StorageMap<FixedBytes<32>, FixedBytes<32>>,
+    block_number: u32,
+    leaf: FixedBytes<32>,
+    start_block: u32,
+    end_block: u32,
+    proof: &[FixedBytes<32>],
+) -> Result<bool, Error> {
+    // the block has to be in the range, the leaves of the tree are blocks start_block + 1..=end_block.
+    if block_number <= start_block || block_number > end_block {
+        return Err(Error::BlockNotInRange(BlockNotInRange {
+            blockNumber: block_number,
+            startBlock: start_block,
+            endBlock: end_block,
+        }));
+    }
+    let key = range_key(start_block, end_block);
+    let commitment = match commitments.try_get(key) {
+        Ok(Some(commitment)) => commitment,
+        // no commitment for the block range.
+        _ => {
+            return Err(Error::RangeCommitmentNotFound(RangeCommitmentNotFound {
+                startBlock: start_block,
+                endBlock: end_block,
+            }))
+        }
+    };
+    // ranges committed before the tree size was stored with them use the current tree size.
+    let tree_size = match Vector::range_tree_sizes().try_get(key) {
+        Ok(Some(tree_size)) => tree_size,
+        _ => Vector::header_range_commitment_tree_size().get(),
+    };
+    commitment_tree::verify(
+        commitment,
+        leaf,
+        block_number - start_block - 1,
+        tree_size,
+        proof,
+    )
+}
+
+/// Returns the key of the commitments for the block range (start_block, end_block], the keccak256 hash of both blocks.
+fn range_key(start_block: u32, end_block: u32) -> FixedBytes<32> {
+    keccak256(
+        KeyPacker {
+            latestBlock: start_block,
+            targetBlock: end_block,
+        }
+        .abi_encode(),
+    )
 }
 
 /// Returns the program vkey hash and program vkey.
 fn get_vkey_hash_and_vkey() -> (Vec<u8>, Vec<u8>) {
-    let vkey_hash = state::get_vec(STATIC_VECTOR_PROGRAM_VKEY_HASH);
-    let vkey = state::get_vec(STATIC_VECTOR_PROGRAM_VKEY);
+    let vkey_hash = Vector::program_vkey_hash().get();
+    let vkey = Vector::program_vkey().get();
     (vkey_hash, vkey)
 }
@@ -0,0 +1,701 @@
+use seq_wasm_sdk::access_control::GUARDIAN;
+use seq_wasm_sdk::migration;
+use seq_wasm_sdk::storage::{StorageMap, StorageValue};
+use seq_wasm_sdk::types::Address;
+use seq_wasm_sdk::utils::TxContext;
+use seq_wasm_sdk::{keccak256, mock, Bytes, FixedBytes, SolEvent, SolInterface, SolType, SolValue};
+use vector_contracts_rust::commitment_tree;
+use vector_contracts_rust::error::{
+    AuthoritySetNotFound, BlockNotInRange, ContractFrozen, Error, InvalidBlockRange,
+    InvalidProofType, MissingRole, NextAuthoritySetExists, ProofLengthMismatch,
+    RangeCommitmentNotFound, RelayerNotApproved, StartBlockMismatch, TrustedBlockMismatch,
+    TrustedHeaderMismatch,
+};
+use vector_contracts_rust::events::{
+    AuthoritySetStored, CheckRelayerUpdated, HeadUpdate, HeaderRangeCommitmentStored,
+};
+use vector_contracts_rust::input_type::{
+    HeaderRangeOutputs, ProofOutputs, ProofType, RotateOutputs,
+};
+use vector_contracts_rust::{
+    authority_set_hash, block_height_to_header_hash, commit_header_range, data_root_commitment,
+    header_range_commitment_tree_size, initializer, latest_authority_set_id, migrate_roles,
+    range_start_block, rotate, set_check_relayer, set_relayer_approval, state_root_commitment,
+    update_block_range_data, update_commitment_tree_size, update_freeze,
+    verify_data_root_inclusion, verify_state_root_inclusion,
+};
+
+type Entrypoint = extern "C" fn(*const TxContext, *const u8, u32) -> bool;
+
+const GENESIS_HEIGHT: u32 = 100;
+const GENESIS_HEADER: FixedBytes<32> = FixedBytes::new([1; 32]);
+const AUTHORITY_SET_ID: u64 = 7;
+const AUTHORITY_SET_HASH: FixedBytes<32> = FixedBytes::new([2; 32]);
+const TREE_SIZE: u32 = 256;
+
+fn guardian() -> Address {
+    Address::new([1; Address::LEN])
+}
+
+fn call(entrypoint: Entrypoint, msg_sender: Address, calldata: &[u8]) -> bool {
+    let tx_ctx = TxContext::builder().msg_sender(msg_sender).build();
+    entrypoint(&tx_ctx, calldata.as_ptr(), calldata.len() as u32)
+}
+
+/// Decodes the error the last failed call reverted with.
+fn revert_error() -> Error {
+    Error::abi_decode(&mock::take_revert_data(), true).unwrap()
+}
+
+fn set_up() {
+    mock::reset();
+    let calldata = (
+        GENESIS_HEIGHT,
+        GENESIS_HEADER,
+        AUTHORITY_SET_ID,
+        AUTHORITY_SET_HASH,
+        TREE_SIZE,
+        Bytes::from(vec![1; 32]),
+        Bytes::from(vec![2; 64]),
+    )
+        .abi_encode_params();
+    assert!(call(initializer, guardian(), &calldata));
+    // drop the role events of the initializer.
+    mock::take_events();
+}
+
+fn block_range_input(start_blocks: Vec<u32>) -> Vec<u8> {
+    let ranges = start_blocks.len();
+    (
+        start_blocks.clone(),
+        start_blocks
+            .iter()
+            .map(|start| start + 10)
+            .collect::<Vec<u32>>(),
+        vec![FixedBytes::<32>::new([3; 32]); ranges],
+        vec![FixedBytes::<32>::new([4; 32]); ranges],
+        vec![FixedBytes::<32>::new([5; 32]); ranges],
+        AUTHORITY_SET_ID,
+        AUTHORITY_SET_HASH,
+    )
+        .abi_encode_params()
+}
+
+/// Calls a view and decodes the value it returned.
+fn view<T: SolValue + From<<T::SolType as SolType>::RustType>>(
+    entrypoint: Entrypoint,
+    calldata: &[u8],
+) -> T {
+    assert!(call(entrypoint, Address::default(), calldata));
+    T::abi_decode(&mock::take_return_data(), true).unwrap()
+}
+
+fn header_range_input(trusted_block: u32, target_block: u32) -> Vec<u8> {
+    let header_range_outputs = HeaderRangeOutputs {
+        trusted_block,
+        trusted_header_hash: GENESIS_HEADER,
+        authority_set_id: AUTHORITY_SET_ID,
+        authority_set_hash: AUTHORITY_SET_HASH,
+        target_block,
+        target_header_hash: FixedBytes::new([3; 32]),
+        state_root_commitment: FixedBytes::new([4; 32]),
+        data_root_commitment: FixedBytes::new([5; 32]),
+        merkle_tree_size: TREE_SIZE,
+    };
+    let public_values = ProofOutputs {
+        proofType: ProofType::HeaderRangeProof,
+        headerRangeOutputs: header_range_outputs.abi_encode().into(),
+        rotateOutputs: vec![].into(),
+    };
+    (
+        Bytes::from(vec![3; 32]),
+        Bytes::from(public_values.abi_encode()),
+    )
+        .abi_encode_params()
+}
+
+fn rotate_input(current_authority_set_id: u64) -> Vec<u8> {
+    let rotate_outputs = RotateOutputs {
+        current_authority_set_id,
+        current_authority_set_hash: AUTHORITY_SET_HASH,
+        new_authority_set_hash: FixedBytes::new([6; 32]),
+    };
+    let public_values = ProofOutputs {
+        proofType: ProofType::RotateProof,
+        headerRangeOutputs: vec![].into(),
+        rotateOutputs: rotate_outputs.abi_encode().into(),
+    };
+    (
+        Bytes::from(vec![3; 32]),
+        Bytes::from(public_values.abi_encode()),
+    )
+        .abi_encode_params()
+}
+
+#[test]
+fn test_migrate_roles() {
+    // state of a contract initialized before layout versioning, with the guardian in slot 2.
+    mock::reset();
+    StorageValue::<bool>::new(0).set(true);
+    StorageValue::<Address>::new(2).set(guardian());
+    StorageValue::<u32>::new(3).set(GENESIS_HEIGHT);
+    StorageValue::<u64>::new(4).set(AUTHORITY_SET_ID);
+    StorageMap::<u32, FixedBytes<32>>::new(1).insert(GENESIS_HEIGHT, GENESIS_HEADER);
+    StorageMap::<u64, FixedBytes<32>>::new(2).insert(AUTHORITY_SET_ID, AUTHORITY_SET_HASH);
+
+    let calldata = (true,).abi_encode_params();
+    assert!(!call(update_freeze, guardian(), &calldata));
+    assert!(!call(migrate_roles, Address::default(), &[]));
+    assert!(call(migrate_roles, guardian(), &[]));
+    assert_eq!(migration::layout_version(), 1);
+    assert!(call(update_freeze, guardian(), &calldata));
+}
+
+#[test]
+fn test_update_freeze_only_guardian() {
+    set_up();
+    let calldata = (true,).abi_encode_params();
+    assert!(!call(update_freeze, Address::default(), &calldata));
+    assert_eq!(
+        revert_error(),
+        Error::MissingRole(MissingRole {
+            role: GUARDIAN,
+            account: Address::default().as_bytes().to_vec().into(),
+        })
+    );
+    assert!(call(update_freeze, guardian(), &calldata));
+
+    mock::set_verify_result(true);
+    let input = header_range_input(GENESIS_HEIGHT, GENESIS_HEIGHT + 10);
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(revert_error(), Error::ContractFrozen(ContractFrozen {}));
+}
+
+#[test]
+fn test_commit_header_range() {
+    set_up();
+    let input = header_range_input(GENESIS_HEIGHT, GENESIS_HEIGHT + 10);
+    assert!(!call(commit_header_range, Address::default(), &input));
+
+    mock::set_verify_result(true);
+    // the trusted header matches, but the trusted block is not the latest block.
+    assert!(!call(
+        commit_header_range,
+        Address::default(),
+        &header_range_input(GENESIS_HEIGHT + 5, GENESIS_HEIGHT + 10)
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::TrustedBlockMismatch(TrustedBlockMismatch {
+            latestBlock: GENESIS_HEIGHT,
+            trustedBlock: GENESIS_HEIGHT + 5,
+        })
+    );
+    assert!(call(commit_header_range, Address::default(), &input));
+    let events = mock::take_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        HeadUpdate::decode_log_data(&events[0], true).unwrap(),
+        HeadUpdate {
+            blockNumber: GENESIS_HEIGHT + 10,
+            headerHash: FixedBytes::new([3; 32]),
+        }
+    );
+    assert_eq!(
+        HeaderRangeCommitmentStored::decode_log_data(&events[1], true).unwrap(),
+        HeaderRangeCommitmentStored {
+            startBlock: GENESIS_HEIGHT,
+            endBlock: GENESIS_HEIGHT + 10,
+            dataCommitment: FixedBytes::new([5; 32]),
+            stateCommitment: FixedBytes::new([4; 32]),
+            headerRangeCommitmentTreeSize: TREE_SIZE,
+        }
+    );
+
+    // trusted block is no longer the latest block.
+    assert!(!call(commit_header_range, Address::default(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::TrustedBlockMismatch(TrustedBlockMismatch {
+            latestBlock: GENESIS_HEIGHT + 10,
+            trustedBlock: GENESIS_HEIGHT,
+        })
+    );
+
+    // trusted header is not the header of the latest block.
+    assert!(!call(
+        commit_header_range,
+        Address::default(),
+        &header_range_input(GENESIS_HEIGHT + 10, GENESIS_HEIGHT + 20)
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::TrustedHeaderMismatch(TrustedHeaderMismatch {
+            expected: FixedBytes::new([3; 32]),
+            found: GENESIS_HEADER,
+        })
+    );
+}
+
+#[test]
+fn test_views() {
+    set_up();
+    assert_eq!(view::<u64>(latest_authority_set_id, &[]), AUTHORITY_SET_ID);
+    assert_eq!(
+        view::<FixedBytes<32>>(authority_set_hash, &(AUTHORITY_SET_ID,).abi_encode_params()),
+        AUTHORITY_SET_HASH
+    );
+    assert_eq!(
+        view::<u32>(header_range_commitment_tree_size, &[]),
+        TREE_SIZE
+    );
+    assert_eq!(
+        view::<FixedBytes<32>>(
+            block_height_to_header_hash,
+            &(GENESIS_HEIGHT,).abi_encode_params()
+        ),
+        GENESIS_HEADER
+    );
+
+    let range = (GENESIS_HEIGHT, GENESIS_HEIGHT + 10).abi_encode_params();
+    assert_eq!(
+        view::<FixedBytes<32>>(data_root_commitment, &range),
+        FixedBytes::ZERO
+    );
+    mock::set_verify_result(true);
+    assert!(call(
+        commit_header_range,
+        Address::default(),
+        &header_range_input(GENESIS_HEIGHT, GENESIS_HEIGHT + 10)
+    ));
+    assert_eq!(
+        view::<FixedBytes<32>>(data_root_commitment, &range),
+        FixedBytes::new([5; 32])
+    );
+    assert_eq!(
+        view::<FixedBytes<32>>(state_root_commitment, &range),
+        FixedBytes::new([4; 32])
+    );
+    assert_eq!(
+        view::<FixedBytes<32>>(
+            block_height_to_header_hash,
+            &(GENESIS_HEIGHT + 10,).abi_encode_params()
+        ),
+        FixedBytes::new([3; 32])
+    );
+
+    // the range key is the keccak256 hash of the ABI encoded start and end block.
+    let key = keccak256(range);
+    assert_eq!(
+        view::<u32>(range_start_block, &(key,).abi_encode_params()),
+        GENESIS_HEIGHT
+    );
+}
+
+#[test]
+fn test_commit_header_range_rejects_rotate_proof() {
+    set_up();
+    mock::set_verify_result(true);
+    assert!(!call(
+        commit_header_range,
+        Address::default(),
+        &rotate_input(AUTHORITY_SET_ID)
+    ));
+    assert_eq!(revert_error(), Error::InvalidProofType(InvalidProofType {}));
+    assert_eq!(mock::verify_calls(), 0);
+}
+
+#[test]
+fn test_rotate() {
+    set_up();
+    mock::set_verify_result(true);
+
+    // unknown authority set.
+    assert!(!call(
+        rotate,
+        Address::default(),
+        &rotate_input(AUTHORITY_SET_ID + 1)
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::AuthoritySetNotFound(AuthoritySetNotFound {
+            authoritySetId: AUTHORITY_SET_ID + 1
+        })
+    );
+
+    assert!(call(
+        rotate,
+        Address::default(),
+        &rotate_input(AUTHORITY_SET_ID)
+    ));
+    let events = mock::take_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        AuthoritySetStored::decode_log_data(&events[0], true).unwrap(),
+        AuthoritySetStored {
+            authoritySetId: AUTHORITY_SET_ID + 1,
+            authoritySetHash: FixedBytes::new([6; 32]),
+        }
+    );
+
+    // next authority set already exists.
+    assert!(!call(
+        rotate,
+        Address::default(),
+        &rotate_input(AUTHORITY_SET_ID)
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::NextAuthoritySetExists(NextAuthoritySetExists {
+            authoritySetId: AUTHORITY_SET_ID + 1
+        })
+    );
+}
+
+#[test]
+fn test_check_relayer() {
+    set_up();
+    mock::set_verify_result(true);
+    let relayer = Address::new([2; Address::LEN]);
+    let relayer_bytes = Bytes::from(relayer.as_bytes().to_vec());
+
+    // only the guardian manages relayers.
+    let calldata = (true,).abi_encode_params();
+    assert!(!call(set_check_relayer, relayer.clone(), &calldata));
+    assert!(call(set_check_relayer, guardian(), &calldata));
+    assert_eq!(
+        CheckRelayerUpdated::decode_log_data(&mock::take_events()[0], true).unwrap(),
+        CheckRelayerUpdated { checkRelayer: true }
+    );
+
+    // submissions from relayers that are not approved fail.
+    let not_approved = Error::RelayerNotApproved(RelayerNotApproved {
+        relayer: relayer_bytes.clone(),
+    });
+    assert!(!call(
+        commit_header_range,
+        relayer.clone(),
+        &header_range_input(GENESIS_HEIGHT, GENESIS_HEIGHT + 10)
+    ));
+    assert_eq!(revert_error(), not_approved);
+    assert!(!call(
+        rotate,
+        relayer.clone(),
+        &rotate_input(AUTHORITY_SET_ID)
+    ));
+    assert_eq!(revert_error(), not_approved);
+
+    let calldata = (relayer_bytes, true).abi_encode_params();
+    assert!(!call(set_relayer_approval, relayer.clone(), &calldata));
+    assert!(call(set_relayer_approval, guardian(), &calldata));
+    assert!(call(
+        commit_header_range,
+        relayer.clone(),
+        &header_range_input(GENESIS_HEIGHT, GENESIS_HEIGHT + 10)
+    ));
+    assert!(call(rotate, relayer, &rotate_input(AUTHORITY_SET_ID)));
+}
+
+#[test]
+fn test_update_block_range_data() {
+    set_up();
+    let input = block_range_input(vec![GENESIS_HEIGHT, GENESIS_HEIGHT + 10]);
+    assert!(!call(update_block_range_data, Address::default(), &input));
+    assert!(call(update_block_range_data, guardian(), &input));
+
+    // start block is no longer the latest block.
+    assert!(!call(update_block_range_data, guardian(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::StartBlockMismatch(StartBlockMismatch {
+            latestBlock: GENESIS_HEIGHT + 20,
+            startBlock: GENESIS_HEIGHT,
+        })
+    );
+}
+
+#[test]
+fn test_update_block_range_data_rolls_back() {
+    set_up();
+    // the third range does not start at the end of the second one.
+    let input = block_range_input(vec![
+        GENESIS_HEIGHT,
+        GENESIS_HEIGHT + 10,
+        GENESIS_HEIGHT + 30,
+    ]);
+    assert!(!call(update_block_range_data, guardian(), &input));
+    assert_eq!(
+        revert_error(),
+        Error::InvalidBlockRange(InvalidBlockRange {
+            endBlock: GENESIS_HEIGHT + 20,
+            nextStartBlock: GENESIS_HEIGHT + 30,
+        })
+    );
+
+    // the ranges stored before the mismatch are discarded with their events.
+    assert!(mock::take_events().is_empty());
+    let range = (GENESIS_HEIGHT, GENESIS_HEIGHT + 10).abi_encode_params();
+    assert_eq!(
+        view::<FixedBytes<32>>(data_root_commitment, &range),
+        FixedBytes::ZERO
+    );
+    assert_eq!(
+        view::<FixedBytes<32>>(
+            block_height_to_header_hash,
+            &(GENESIS_HEIGHT + 10,).abi_encode_params()
+        ),
+        FixedBytes::ZERO
+    );
+    assert!(call(
+        update_block_range_data,
+        guardian(),
+        &block_range_input(vec![GENESIS_HEIGHT])
+    ));
+}
+
+#[test]
+fn test_update_block_range_data_over_64_kib() {
+    set_up();
+    let ranges = 500;
+    let start_blocks: Vec<u32> = (0..ranges).map(|i| GENESIS_HEIGHT + i * 10).collect();
+    let input = block_range_input(start_blocks);
+    assert!(input.len() > u16::MAX as usize);
+    assert!(call(update_block_range_data, guardian(), &input));
+}
+
+/// Returns the roots of the blocks (GENESIS_HEIGHT, GENESIS_HEIGHT + 10], starting at `first`.
+fn block_roots(first: u8) -> Vec<FixedBytes<32>> {
+    (0..10).map(|i| FixedBytes::new([first + i; 32])).collect()
+}
+
+/// Stores the commitments of the roots of the blocks (GENESIS_HEIGHT, GENESIS_HEIGHT + 10].
+fn store_block_range(data_roots: &[FixedBytes<32>], state_roots: &[FixedBytes<32>]) {
+    let input = (
+        vec![GENESIS_HEIGHT],
+        vec![GENESIS_HEIGHT + 10],
+        vec![FixedBytes::<32>::new([3; 32])],
+        vec![commitment_tree::root(data_roots, TREE_SIZE).unwrap()],
+        vec![commitment_tree::root(state_roots, TREE_SIZE).unwrap()],
+        AUTHORITY_SET_ID,
+        AUTHORITY_SET_HASH,
+    )
+        .abi_encode_params();
+    assert!(call(update_block_range_data, guardian(), &input));
+}
+
+fn inclusion_input(
+    block_number: u32,
+    root: FixedBytes<32>,
+    end_block: u32,
+    proof: Vec<FixedBytes<32>>,
+) -> Vec<u8> {
+    (block_number, root, GENESIS_HEIGHT, end_block, proof).abi_encode_params()
+}
+
+#[test]
+fn test_commitment_tree() {
+    let leaves = block_roots(10);
+    let root = commitment_tree::root(&leaves, 16).unwrap();
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = commitment_tree::prove(&leaves, index as u32, 16).unwrap();
+        assert_eq!(proof.len(), 4);
+        assert!(commitment_tree::verify(root, *leaf, index as u32, 16, &proof).unwrap());
+        assert!(!commitment_tree::verify(root, *leaf, index as u32 ^ 1, 16, &proof).unwrap());
+    }
+    // the padding leaves are zero.
+    let proof = commitment_tree::prove(&leaves, 15, 16).unwrap();
+    assert!(commitment_tree::verify(root, FixedBytes::ZERO, 15, 16, &proof).unwrap());
+
+    assert!(commitment_tree::root(&leaves, 12).is_err());
+    assert!(commitment_tree::root(&leaves, 8).is_err());
+    assert!(commitment_tree::verify(root, leaves[0], 16, 16, &proof).is_err());
+}
+
+#[test]
+fn test_verify_data_root_inclusion() {
+    set_up();
+    let data_roots = block_roots(10);
+    store_block_range(&data_roots, &block_roots(20));
+    let end_block = GENESIS_HEIGHT + 10;
+
+    // the first leaf is the block after the start block.
+    for (index, data_root) in data_roots.iter().enumerate() {
+        let proof = commitment_tree::prove(&data_roots, index as u32, TREE_SIZE).unwrap();
+        let block_number = GENESIS_HEIGHT + 1 + index as u32;
+        assert!(view::<bool>(
+            verify_data_root_inclusion,
+            &inclusion_input(block_number, *data_root, end_block, proof)
+        ));
+    }
+
+    // wrong block, wrong data root.
+    let proof = commitment_tree::prove(&data_roots, 0, TREE_SIZE).unwrap();
+    assert!(!view::<bool>(
+        verify_data_root_inclusion,
+        &inclusion_input(GENESIS_HEIGHT + 2, data_roots[0], end_block, proof.clone())
+    ));
+    assert!(!view::<bool>(
+        verify_data_root_inclusion,
+        &inclusion_input(GENESIS_HEIGHT + 1, data_roots[1], end_block, proof.clone())
+    ));
+
+    // block outside of the range.
+    for block_number in [GENESIS_HEIGHT, end_block + 1] {
+        assert!(!call(
+            verify_data_root_inclusion,
+            Address::default(),
+            &inclusion_input(block_number, data_roots[0], end_block, proof.clone())
+        ));
+        assert_eq!(
+            revert_error(),
+            Error::BlockNotInRange(BlockNotInRange {
+                blockNumber: block_number,
+                startBlock: GENESIS_HEIGHT,
+                endBlock: end_block,
+            })
+        );
+    }
+
+    // no commitment for the range.
+    assert!(!call(
+        verify_data_root_inclusion,
+        Address::default(),
+        &inclusion_input(
+            GENESIS_HEIGHT + 1,
+            data_roots[0],
+            end_block + 1,
+            proof.clone()
+        )
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::RangeCommitmentNotFound(RangeCommitmentNotFound {
+            startBlock: GENESIS_HEIGHT,
+            endBlock: end_block + 1,
+        })
+    );
+
+    // proof of the wrong depth.
+    assert!(!call(
+        verify_data_root_inclusion,
+        Address::default(),
+        &inclusion_input(
+            GENESIS_HEIGHT + 1,
+            data_roots[0],
+            end_block,
+            proof[1..].to_vec()
+        )
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::ProofLengthMismatch(ProofLengthMismatch {
+            expected: 8,
+            found: 7,
+        })
+    );
+}
+
+#[test]
+fn test_verify_inclusion_after_tree_size_update() {
+    set_up();
+    let data_roots = block_roots(10);
+    store_block_range(&data_roots, &block_roots(20));
+    let new_tree_size = TREE_SIZE * 2;
+    let calldata = (new_tree_size,).abi_encode_params();
+    assert!(call(update_commitment_tree_size, guardian(), &calldata));
+
+    // the range stored before the update is verified against the tree size it was committed with.
+    let proof = commitment_tree::prove(&data_roots, 0, TREE_SIZE).unwrap();
+    assert!(view::<bool>(
+        verify_data_root_inclusion,
+        &inclusion_input(
+            GENESIS_HEIGHT + 1,
+            data_roots[0],
+            GENESIS_HEIGHT + 10,
+            proof
+        )
+    ));
+
+    // a range stored after the update uses the new tree size.
+    let next_roots = block_roots(30);
+    let input = (
+        vec![GENESIS_HEIGHT + 10],
+        vec![GENESIS_HEIGHT + 20],
+        vec![FixedBytes::<32>::new([3; 32])],
+        vec![commitment_tree::root(&next_roots, new_tree_size).unwrap()],
+        vec![FixedBytes::<32>::new([4; 32])],
+        AUTHORITY_SET_ID,
+        AUTHORITY_SET_HASH,
+    )
+        .abi_encode_params();
+    assert!(call(update_block_range_data, guardian(), &input));
+    let proof = commitment_tree::prove(&next_roots, 0, new_tree_size).unwrap();
+    let calldata = (
+        GENESIS_HEIGHT + 11,
+        next_roots[0],
+        GENESIS_HEIGHT + 10,
+        GENESIS_HEIGHT + 20,
+        proof.clone(),
+    )
+        .abi_encode_params();
+    assert!(view::<bool>(verify_data_root_inclusion, &calldata));
+    let calldata = (
+        GENESIS_HEIGHT + 11,
+        next_roots[0],
+        GENESIS_HEIGHT + 10,
+        GENESIS_HEIGHT + 20,
+        proof[1..].to_vec(),
+    )
+        .abi_encode_params();
+    assert!(!call(
+        verify_data_root_inclusion,
+        Address::default(),
+        &calldata
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::ProofLengthMismatch(ProofLengthMismatch {
+            expected: 9,
+            found: 8,
+        })
+    );
+}
+
+#[test]
+fn test_verify_state_root_inclusion() {
+    set_up();
+    let data_roots = block_roots(10);
+    let state_roots = block_roots(20);
+    store_block_range(&data_roots, &state_roots);
+    let end_block = GENESIS_HEIGHT + 10;
+
+    for (index, state_root) in state_roots.iter().enumerate() {
+        let proof = commitment_tree::prove(&state_roots, index as u32, TREE_SIZE).unwrap();
+        let block_number = GENESIS_HEIGHT + 1 + index as u32;
+        assert!(view::<bool>(
+            verify_state_root_inclusion,
+            &inclusion_input(block_number, *state_root, end_block, proof)
+        ));
+    }
+
+    // a data root proof does not verify against the state root commitment.
+    let proof = commitment_tree::prove(&data_roots, 0, TREE_SIZE).unwrap();
+    assert!(!view::<bool>(
+        verify_state_root_inclusion,
+        &inclusion_input(GENESIS_HEIGHT + 1, data_roots[0], end_block, proof.clone())
+    ));
+
+    assert!(!call(
+        verify_state_root_inclusion,
+        Address::default(),
+        &inclusion_input(end_block + 1, state_roots[0], end_block, proof)
+    ));
+    assert_eq!(
+        revert_error(),
+        Error::BlockNotInRange(BlockNotInRange {
+            blockNumber: end_block + 1,
+            startBlock: GENESIS_HEIGHT,
+            endBlock: end_block,
+        })
+    );
+}